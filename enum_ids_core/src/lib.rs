@@ -0,0 +1,52 @@
+#![warn(clippy::all, clippy::pedantic, clippy::cargo)]
+
+//! Traits, which are implemented by `#[enum_ids]` macro. These traits allow writing generic
+//! code, which works with any annotated enum.
+//!
+//! Use this crate via `enum_ids` crate, which re-exports everything from here.
+
+/// Implemented for the source enum annotated with `#[enum_ids]`.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_ids::{enum_ids, HasId, IdEnum};
+///
+/// #[enum_ids]
+/// #[derive(Debug, Clone)]
+/// pub enum Kind {
+///     A(i32),
+///     B { value: String },
+///     C,
+/// }
+///
+/// fn kind_name<T: HasId>(value: &T) -> &'static str
+/// where
+///     T::Id: IdEnum,
+/// {
+///     value.id().name()
+/// }
+///
+/// assert_eq!(kind_name(&Kind::A(1)), "A");
+/// ```
+pub trait HasId {
+    /// Type of ID. Usually this is the generated ID enum, or `Option` of the generated ID enum
+    /// if some variants are excluded with `#[enum_ids(skip)]`.
+    type Id;
+
+    /// Returns the corresponding ID variant for the enum instance.
+    fn id(&self) -> Self::Id;
+}
+
+/// Implemented for the generated ID enum.
+pub trait IdEnum: Sized + 'static {
+    /// Number of variants of the ID enum.
+    const COUNT: usize;
+
+    /// Returns all variants of the ID enum in the order of declaration.
+    fn all() -> &'static [Self];
+
+    /// Returns the name of the variant. The name takes into account `rename` and
+    /// `display_variant_snake`, but doesn't include the name of the enum.
+    fn name(&self) -> &'static str;
+}
@@ -0,0 +1,173 @@
+use crate::{case::Case, context::Context, get_ident, get_source_methods, value, variant::Variant};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Fields, ItemEnum, Type};
+
+/// Generates accessors of payloads of variants for the source enum: `as_a()`, `as_a_mut()` and
+/// `into_a()` for every variant with fields.
+///
+/// A variant with a single field gives the field; a variant with several fields (unnamed or
+/// named) gives a tuple of fields in the order of declaration. Consuming accessors return
+/// `Result<_, Self>`, so the value isn't lost on mismatch.
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - All variants of the source enum.
+/// * `input` - The source enum.
+///
+/// # Returns
+///
+/// * Generated code or an error if two variants give the same name of accessor or an accessor
+///   conflicts with another generated method of the source enum.
+pub fn get_accessors_impl(
+    cx: &Context,
+    variants: &[Variant],
+    input: &ItemEnum,
+) -> Result<TokenStream, syn::Error> {
+    if !cx.accessors() {
+        return Ok(quote! {});
+    }
+    let src = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut reserved = get_source_methods(cx, variants, src);
+    reserved.extend(value::get_names(cx, variants));
+    let mut names: Vec<(String, &Variant)> = Vec::new();
+    let mut accessors = Vec::new();
+    for variant in variants {
+        let (bindings, types) = get_fields(&variant.fields);
+        if bindings.is_empty() {
+            continue;
+        }
+        let name = Case::Snake.convert(variant.ident.to_string());
+        for method in [
+            format!("as_{name}"),
+            format!("as_{name}_mut"),
+            format!("into_{name}"),
+        ] {
+            check_accessor(&method, variant, &reserved, &names)?;
+            names.push((method, variant));
+        }
+        let span = variant.ident.span();
+        let as_ident = get_ident(&format!("as_{name}"), span);
+        let as_mut_ident = get_ident(&format!("as_{name}_mut"), span);
+        let into_ident = get_ident(&format!("into_{name}"), span);
+        let pattern = get_pattern(src, variant, &bindings);
+        let (values, refs, muts, owned) = if bindings.len() == 1 {
+            let ty = types[0];
+            (
+                quote! { #(#bindings)* },
+                quote! { &#ty },
+                quote! { &mut #ty },
+                quote! { #ty },
+            )
+        } else {
+            (
+                quote! { (#(#bindings),*) },
+                quote! { (#(&#types),*) },
+                quote! { (#(&mut #types),*) },
+                quote! { (#(#types),*) },
+            )
+        };
+        let cfg = &variant.cfg_attrs;
+        let variant_ident = &variant.ident;
+        let as_doc =
+            format!(" Returns references to the fields if the value is `{src}::{variant_ident}`.");
+        let as_mut_doc = format!(
+            " Returns mutable references to the fields if the value is `{src}::{variant_ident}`."
+        );
+        let into_doc = format!(
+            " Returns the fields if the value is `{src}::{variant_ident}`; otherwise returns the value back."
+        );
+        accessors.push(quote! {
+            #(#cfg)*
+            #[doc = #as_doc]
+            #[allow(unreachable_patterns)]
+            pub const fn #as_ident(&self) -> Option<#refs> {
+                match self {
+                    #pattern => Some(#values),
+                    _ => None,
+                }
+            }
+
+            #(#cfg)*
+            #[doc = #as_mut_doc]
+            #[allow(unreachable_patterns)]
+            pub fn #as_mut_ident(&mut self) -> Option<#muts> {
+                match self {
+                    #pattern => Some(#values),
+                    _ => None,
+                }
+            }
+
+            #(#cfg)*
+            #[doc = #into_doc]
+            #[allow(unreachable_patterns)]
+            pub fn #into_ident(self) -> Result<#owned, Self> {
+                match self {
+                    #pattern => Ok(#values),
+                    other => Err(other),
+                }
+            }
+        });
+    }
+    Ok(quote! {
+        impl #impl_generics #src #ty_generics #where_clause {
+            #(#accessors)*
+        }
+    })
+}
+
+/// Checks that the accessor of the variant has a name, which isn't used by accessors of other
+/// variants or by other generated methods of the source enum.
+fn check_accessor(
+    method: &str,
+    variant: &Variant,
+    reserved: &[String],
+    names: &[(String, &Variant)],
+) -> Result<(), syn::Error> {
+    if reserved.iter().any(|name| name == method) {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            format!("Accessor \"{method}\" of the variant conflicts with the generated method"),
+        ));
+    }
+    if let Some((_, first)) = names.iter().find(|(n, _)| n == method) {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            format!(
+                "Variants \"{}\" and \"{}\" have the same accessor \"{method}\"",
+                first.ident, variant.ident
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the names of bindings and the types of the fields of the variant.
+fn get_fields(fields: &Fields) -> (Vec<Ident>, Vec<&Type>) {
+    match fields {
+        Fields::Unit => (Vec::new(), Vec::new()),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (format_ident!("field_{}", i), &field.ty))
+            .unzip(),
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|field| field.ident.clone().map(|ident| (ident, &field.ty)))
+            .unzip(),
+    }
+}
+
+/// Returns the pattern, which binds all fields of the variant.
+fn get_pattern(src: &Ident, variant: &Variant, bindings: &[Ident]) -> TokenStream {
+    let ident = &variant.ident;
+    if let Fields::Named(_) = &variant.fields {
+        quote! { #src::#ident { #(#bindings),* } }
+    } else {
+        quote! { #src::#ident(#(#bindings),*) }
+    }
+}
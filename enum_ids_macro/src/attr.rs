@@ -0,0 +1,434 @@
+use crate::value::Value;
+use proc_macro2::TokenStream;
+use std::{convert::TryFrom, fmt};
+use syn::{punctuated::Punctuated, Path, Token};
+
+/// Represents the various attributes that can be applied to the `enum_ids` procedural macro.
+///
+/// This enum is used to parse and handle different attributes provided to the macro,
+/// allowing customization of its behavior such as controlling trait derivations,
+/// method naming, visibility, and the naming of the generated enum.
+#[derive(Clone, Debug)]
+pub enum Attr {
+    /// Adds implementation of `std::fmt::Display` for origin enum; can be used only with unnamed
+    /// fields like `Field(value)` and will add convertation `Field(value) => value.to_string()`
+    DisplayFromValue,
+
+    /// Adds implementation of `std::fmt::Display`
+    Display,
+
+    /// Sets the doc comment of the generated enum instead of the default one.
+    ///
+    /// The associated `String` specifies the doc comment. Other generated types keep their default
+    /// doc comments.
+    Doc(String),
+
+    /// Adds implementation of `std::fmt::Display` without reference to name of enum
+    DisplayVariant,
+
+    /// Adds implementation of `std::fmt::Display` without reference to name of enum and convert name into snake case.
+    /// Same as `display_variant, rename_all = "snake_case"`
+    DisplayVariantSnake,
+
+    /// Converts names of variants into the given case. Names are used by display and parse implementations.
+    ///
+    /// The associated `String` specifies the case (`snake_case`, `kebab-case`, `camelCase` etc).
+    RenameAll(String),
+
+    /// Adds method `as_vec()`, which returns all possible variants of the enum. Fields of variants are
+    /// created with `Default` or with the expression of `#[enum_ids(sample = ...)]`
+    Iterator,
+
+    /// Adds implementations of `std::str::FromStr`, `TryFrom<&str>` and `TryFrom<String>` for generated enum.
+    /// Accepts the same strings, which are produced by the active display mode
+    Parse,
+
+    /// Same as `Parse`, but compares strings without taking into account ASCII case
+    ParseIgnoreCase,
+
+    /// Adds predicates `is_a()`, `is_b()` etc. and `is_one_of(&[KindId])` to the source enum
+    Predicates,
+
+    /// Adds accessors `as_a()`, `as_a_mut()` and `into_a()` of payloads of variants to the source enum
+    Accessors,
+
+    /// Adds type `KindIdSet`, a set of IDs backed by a bit mask, and macro `kind_id_set![..]`, which is
+    /// local to the module of the enum
+    Set,
+
+    /// Adds type `KindIdMap<V>`, a map with a value for every ID backed by an array
+    Map,
+
+    /// Adds navigation of generated enum in the order of declaration: `index()`, `from_index()`,
+    /// `next()`, `prev()`, `next_cyclic()`, `prev_cyclic()` and `range()`
+    Ordinal,
+
+    /// Implements `Serialize` and `Deserialize` for the generated enum (requires feature `serde`).
+    ///
+    /// The associated `String` specifies the format: `name` or `index`.
+    Serde(String),
+
+    /// Makes the deserializer of the generated enum accept both names and indexes
+    SerdeAny,
+
+    /// Adds the listed attributes to the generated enum as they are, e.g. `attrs(non_exhaustive, repr(u8))`.
+    ///
+    /// The associated `Vec` contains the tokens of attributes without `#[...]`.
+    Attrs(Vec<TokenStream>),
+
+    /// Copies the attributes with the given names from the source enum to the generated enum.
+    ///
+    /// The associated `String` contains a comma-separated list of attribute names.
+    InheritAttrs(String),
+
+    /// Declares typed values of variants, e.g. `value(status: u16 = 500)`. Adds a `const fn` for
+    /// every value to both enums.
+    ///
+    /// The associated `Vec` contains the declarations; the expression is the default value.
+    Value(Vec<Value>),
+
+    /// Prevents the copying of any `derive` attributes from the source enum to the generated enum.
+    NoDerive,
+
+    /// Specifies additional traits to derive for the generated enum.
+    ///
+    /// The associated list contains the paths of traits with their spans.
+    Derive(Punctuated<Path, Token![,]>),
+
+    /// Defines a custom name for the getter method instead of the default `id()`.
+    ///
+    /// The associated `String` specifies the desired method name.
+    Getter(String),
+
+    /// Adds the method of the generated enum, which returns the iterator `KindIdIter` over all IDs,
+    /// and type `KindIds`, which implements `IntoIterator` over all IDs.
+    ///
+    /// The associated `Option<String>` specifies the name of the method. If the name isn't defined,
+    /// `iter()` is used.
+    Iter(Option<String>),
+
+    /// Defines a custom name for the type, which implements `IntoIterator` over all IDs, instead of
+    /// the default `KindIds`.
+    ///
+    /// The associated `String` specifies the desired type name.
+    IterAll(String),
+
+    /// Sets a custom name for the generated enum instead of the default naming convention (`ParentNameId`).
+    ///
+    /// The associated `String` specifies the desired enum name.
+    EnumName(String),
+
+    /// Adds `#[repr(..)]` to the generated enum and conversions from/into integer.
+    ///
+    /// The associated `Option<String>` specifies the integer type. If the type isn't defined,
+    /// the smallest unsigned type, which can hold all variants, is used.
+    Repr(Option<String>),
+
+    /// Makes the getter panic on variants excluded with `#[enum_ids(skip)]` instead of returning `Option`.
+    PanicOnSkip,
+
+    /// Sets the visibility of the generated enum to private, overriding any inherited visibility.
+    NotPublic,
+
+    /// Sets the visibility of the generated enum to public, regardless of the source enum's visibility.
+    Public,
+
+    /// Drops the default of the attribute with the given name, which is read from the config
+    /// (`key = false`).
+    Unset(String),
+}
+
+/// Formats, which can be used with `serde`
+pub const SERDE_FORMATS: &[&str] = &["name", "index"];
+
+/// Attributes, which can be defined more than once; their lists are merged
+pub const LISTS: &[&str] = &["attrs", "value"];
+
+/// Attributes of variants, which can be defined more than once; their lists are merged
+pub const VARIANT_LISTS: &[&str] = &["value"];
+
+/// Pairs of attributes of variants, which cannot be used together. `value(...)` can be used with
+/// `skip`, because values are available for skipped variants of the source enum.
+pub const VARIANT_CONFLICTS: &[(&str, &str)] = &[
+    ("skip", "nested"),
+    ("skip", "rename"),
+    ("skip", "rename_id"),
+    ("skip", "sample"),
+];
+
+/// Pairs of attributes, which cannot be used together
+pub const CONFLICTS: &[(&str, &str)] = &[
+    ("public", "not_public"),
+    ("derive", "no_derive"),
+    ("display", "display_variant"),
+    ("display", "display_variant_snake"),
+    ("display_variant", "display_variant_snake"),
+    ("parse", "parse_ignore_case"),
+];
+
+/// Options of groups like `display(variant, rename_all = "snake_case")`. An option is either the
+/// name of an attribute without the prefix of the group (`variant` is `display_variant`) or the
+/// name of an attribute as it is (`rename_all`).
+pub const GROUPS: &[(&str, &[&str])] = &[
+    (
+        "display",
+        &["variant", "variant_snake", "from_value", "rename_all"],
+    ),
+    ("parse", &["ignore_case"]),
+];
+
+/// Integer types, which can be used with `repr`
+pub const REPR_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+impl Attr {
+    /// Returns all attributes with empty values; used to recognize attributes by name.
+    fn all() -> Vec<Attr> {
+        vec![
+            Attr::Derive(Punctuated::new()),
+            Attr::Getter(String::new()),
+            Attr::Iter(None),
+            Attr::IterAll(String::new()),
+            Attr::EnumName(String::new()),
+            Attr::RenameAll(String::new()),
+            Attr::Repr(None),
+            Attr::Doc(String::new()),
+            Attr::Attrs(Vec::new()),
+            Attr::InheritAttrs(String::new()),
+            Attr::Value(Vec::new()),
+            Attr::Serde(String::new()),
+            Attr::SerdeAny,
+            Attr::NoDerive,
+            Attr::Display,
+            Attr::DisplayVariant,
+            Attr::DisplayVariantSnake,
+            Attr::Iterator,
+            Attr::Parse,
+            Attr::ParseIgnoreCase,
+            Attr::DisplayFromValue,
+            Attr::Map,
+            Attr::Ordinal,
+            Attr::Predicates,
+            Attr::Accessors,
+            Attr::Set,
+            Attr::PanicOnSkip,
+            Attr::NotPublic,
+            Attr::Public,
+        ]
+    }
+}
+
+impl TryFrom<&str> for Attr {
+    type Error = String;
+
+    /// Attempts to convert a string slice to an `Attr` variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A string slice that holds the attribute name.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Attr)` if the string matches a known attribute.
+    /// * `Err(String)` if the attribute is unknown.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Attr::all()
+            .into_iter()
+            .find(|attr| attr.to_string() == value)
+            .ok_or_else(|| {
+                unknown(
+                    &format!("Unknown attribute \"{value}\""),
+                    value,
+                    Attr::all().iter().map(ToString::to_string),
+                )
+            })
+    }
+}
+
+impl fmt::Display for Attr {
+    /// Formats the `Attr` variant as a string.
+    ///
+    /// This is useful for converting the enum variants back to their string representations.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter.
+    ///
+    /// # Returns
+    ///
+    /// * `fmt::Result` indicating success or failure.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Derive(..) => "derive",
+                Self::Getter(..) => "getter",
+                Self::Iter(..) => "iter",
+                Self::IterAll(..) => "iter_all",
+                Self::EnumName(..) => "name",
+                Self::Repr(..) => "repr",
+                Self::RenameAll(..) => "rename_all",
+                Self::Doc(..) => "doc",
+                Self::Attrs(..) => "attrs",
+                Self::InheritAttrs(..) => "inherit_attrs",
+                Self::Value(..) => "value",
+                Self::Serde(..) => "serde",
+                Self::SerdeAny => "serde_any",
+                Self::Display => "display",
+                Self::DisplayVariant => "display_variant",
+                Self::DisplayVariantSnake => "display_variant_snake",
+                Self::Iterator => "iterator",
+                Self::Parse => "parse",
+                Self::ParseIgnoreCase => "parse_ignore_case",
+                Self::DisplayFromValue => "display_from_value",
+                Self::NoDerive => "no_derive",
+                Self::Predicates => "predicates",
+                Self::Accessors => "accessors",
+                Self::Set => "set",
+                Self::Map => "map",
+                Self::Ordinal => "ordinal",
+                Self::PanicOnSkip => "panic_on_skip",
+                Self::NotPublic => "not_public",
+                Self::Public => "public",
+                Self::Unset(name) => name,
+            }
+        )
+    }
+}
+
+/// Represents the attributes, which can be applied to a single variant of the source enum
+/// with `#[enum_ids(...)]`.
+#[derive(Clone, Debug)]
+pub enum VariantAttr {
+    /// Sets the string, which is used for the variant by display and parse implementations
+    /// instead of the name of the variant.
+    ///
+    /// The associated `String` specifies the desired string.
+    Rename(String),
+
+    /// Sets the name of the variant in the generated enum instead of the name of the source variant.
+    ///
+    /// The associated `String` specifies the desired identifier.
+    RenameId(String),
+
+    /// Excludes the variant from the generated enum.
+    Skip,
+
+    /// Marks the variant, which wraps another enum with IDs. The ID of the wrapped enum is
+    /// included into the path of the variant returned by `deep_id()`.
+    Nested,
+
+    /// Sets typed values of the variant, e.g. `value(status: u16 = 404)`.
+    ///
+    /// The associated `Vec` contains the values.
+    Value(Vec<Value>),
+
+    /// Sets the expression, which creates a sample of the variant for `iterator`, e.g.
+    /// `sample = Kind::A(1)`. By default fields are created with `Default`.
+    ///
+    /// The associated `TokenStream` contains the expression.
+    Sample(TokenStream),
+}
+
+impl VariantAttr {
+    /// Returns all attributes of variants with empty values; used to recognize attributes by name.
+    fn all() -> Vec<VariantAttr> {
+        vec![
+            VariantAttr::Rename(String::new()),
+            VariantAttr::RenameId(String::new()),
+            VariantAttr::Skip,
+            VariantAttr::Nested,
+            VariantAttr::Value(Vec::new()),
+            VariantAttr::Sample(TokenStream::new()),
+        ]
+    }
+}
+
+impl TryFrom<&str> for VariantAttr {
+    type Error = String;
+
+    /// Attempts to convert a string slice to a `VariantAttr` variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A string slice that holds the attribute name.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VariantAttr)` if the string matches a known attribute.
+    /// * `Err(String)` if the attribute is unknown.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        VariantAttr::all()
+            .into_iter()
+            .find(|attr| attr.to_string() == value)
+            .ok_or_else(|| {
+                unknown(
+                    &format!("Unknown variant's attribute \"{value}\""),
+                    value,
+                    VariantAttr::all().iter().map(ToString::to_string),
+                )
+            })
+    }
+}
+
+impl fmt::Display for VariantAttr {
+    /// Formats the `VariantAttr` variant as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Rename(..) => "rename",
+                Self::RenameId(..) => "rename_id",
+                Self::Skip => "skip",
+                Self::Nested => "nested",
+                Self::Value(..) => "value",
+                Self::Sample(..) => "sample",
+            }
+        )
+    }
+}
+
+/// Builds the error of an unknown attribute with a suggestion of the closest known name.
+///
+/// # Arguments
+///
+/// * `message` - The message of the error.
+/// * `value` - The unknown name.
+/// * `names` - All known names.
+///
+/// # Returns
+///
+/// * The message with "did you mean" suggestion, if a similar name is found.
+fn unknown<I: Iterator<Item = String>>(message: &str, value: &str, names: I) -> String {
+    let suggestion = names
+        .map(|name| (distance(value, &name), name))
+        .filter(|(distance, _)| *distance <= 2.max(value.len() / 3))
+        .min_by_key(|(distance, _)| *distance);
+    match suggestion {
+        Some((_, name)) => format!("{message}; did you mean `{name}`?"),
+        None => message.to_owned(),
+    }
+}
+
+/// Returns the Levenshtein distance between two strings.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j + 1])
+            };
+            prev = current;
+        }
+    }
+    row[b.len()]
+}
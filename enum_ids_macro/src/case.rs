@@ -0,0 +1,181 @@
+use std::{convert::TryFrom, fmt};
+
+/// Represents the case conversions, which can be used with `rename_all`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Case {
+    /// `snake_case`
+    Snake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `lowercase`
+    Lower,
+    /// `UPPERCASE`
+    Upper,
+    /// `Title Case`
+    Title,
+}
+
+impl Case {
+    /// All supported case conversions.
+    pub const ALL: [Case; 9] = [
+        Case::Snake,
+        Case::Kebab,
+        Case::ScreamingSnake,
+        Case::ScreamingKebab,
+        Case::Camel,
+        Case::Pascal,
+        Case::Lower,
+        Case::Upper,
+        Case::Title,
+    ];
+
+    /// Converts the name into the case.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to convert, usually the identifier of a variant.
+    ///
+    /// # Returns
+    ///
+    /// * The converted name.
+    pub fn convert<S: AsRef<str>>(self, name: S) -> String {
+        let words = split(name.as_ref());
+        match self {
+            Case::Snake => join(&words, "_", str::to_lowercase),
+            Case::Kebab => join(&words, "-", str::to_lowercase),
+            Case::ScreamingSnake => join(&words, "_", str::to_uppercase),
+            Case::ScreamingKebab => join(&words, "-", str::to_uppercase),
+            Case::Lower => join(&words, "", str::to_lowercase),
+            Case::Upper => join(&words, "", str::to_uppercase),
+            Case::Pascal => join(&words, "", capitalize),
+            Case::Title => join(&words, " ", capitalize),
+            Case::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Splits the name into words.
+///
+/// A new word starts after `_`, `-` or a space, at a transition from a lowercase letter or
+/// a digit to an uppercase letter, and at the last uppercase letter of an acronym followed by
+/// a lowercase letter (`HTTPServer` gives `HTTP` and `Server`). Digits belong to the preceding word.
+fn split(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let chars: Vec<(usize, char)> = name.char_indices().collect();
+    let mut start: Option<usize> = None;
+    for (i, &(pos, c)) in chars.iter().enumerate() {
+        if matches!(c, '_' | '-' | ' ') {
+            if let Some(begin) = start.take() {
+                words.push(&name[begin..pos]);
+            }
+            continue;
+        }
+        if let Some(begin) = start {
+            let prev = chars[i - 1].1;
+            let next = chars.get(i + 1).map(|&(_, c)| c);
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next.is_some_and(char::is_lowercase)));
+            if boundary {
+                words.push(&name[begin..pos]);
+                start = Some(pos);
+            }
+        } else {
+            start = Some(pos);
+        }
+    }
+    if let Some(begin) = start {
+        words.push(&name[begin..]);
+    }
+    words
+}
+
+/// Converts every word with `convert` and joins words with `separator`.
+fn join(words: &[&str], separator: &str, convert: fn(&str) -> String) -> String {
+    words
+        .iter()
+        .map(|word| convert(word))
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+/// Makes the first letter of the word uppercase and other letters lowercase.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect()
+    })
+}
+
+impl TryFrom<&str> for Case {
+    type Error = String;
+
+    /// Attempts to convert a string slice to a `Case` variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A string slice that holds the name of the case.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Case)` if the string matches a known case.
+    /// * `Err(String)` if the case is unknown.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Case::ALL
+            .into_iter()
+            .find(|case| case.to_string() == value)
+            .ok_or_else(|| {
+                format!(
+                    "Unknown case \"{value}\"; expecting one of: {}",
+                    Case::ALL
+                        .iter()
+                        .map(|case| format!("\"{case}\""))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for Case {
+    /// Formats the `Case` variant as it's used in `rename_all`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Snake => "snake_case",
+                Self::Kebab => "kebab-case",
+                Self::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+                Self::ScreamingKebab => "SCREAMING-KEBAB-CASE",
+                Self::Camel => "camelCase",
+                Self::Pascal => "PascalCase",
+                Self::Lower => "lowercase",
+                Self::Upper => "UPPERCASE",
+                Self::Title => "Title Case",
+            }
+        )
+    }
+}
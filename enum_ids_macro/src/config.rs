@@ -0,0 +1,322 @@
+use crate::{combine_errors, context::Context, get_ident, is_ident};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+use syn::LitStr;
+
+/// Name of the file with defaults of attributes, which is looked up in the root of the crate.
+const FILE: &str = "enum_ids.toml";
+
+/// Name of the table of `Cargo.toml` with defaults of attributes.
+const METADATA: &str = "[package.metadata.enum_ids]";
+
+/// Defaults of attributes shared by all enums of the crate.
+///
+/// Defaults are read from `enum_ids.toml` in `CARGO_MANIFEST_DIR` or, if there is no such file,
+/// from the table `[package.metadata.enum_ids]` of `Cargo.toml`. Keys are names of attributes:
+///
+/// - `key = true` is `key` (`false` is ignored);
+/// - `key = "value"` is `key = "value"`;
+/// - `key = ["a", "b"]` is `key(a, b)`, e.g. `derive = ["Debug", "Clone"]`.
+#[derive(Default)]
+pub struct Config {
+    /// The defaults of attributes.
+    pub defaults: Context,
+    /// The path to `enum_ids.toml`, which is tracked to rebuild the crate on changes.
+    file: Option<PathBuf>,
+}
+
+impl Config {
+    /// Reads the defaults of the crate being compiled.
+    ///
+    /// # Returns
+    ///
+    /// * The defaults (empty if there is no config) or an error if the config cannot be read.
+    pub fn load() -> Result<Config, syn::Error> {
+        match env::var_os("CARGO_MANIFEST_DIR") {
+            Some(dir) => Config::from_dir(Path::new(&dir)),
+            None => Ok(Config::default()),
+        }
+    }
+
+    /// Reads the defaults from the root directory of a crate.
+    fn from_dir(dir: &Path) -> Result<Config, syn::Error> {
+        let file = dir.join(FILE);
+        if file.is_file() {
+            return Ok(Config {
+                defaults: get_defaults(&read(&file)?, FILE)?,
+                file: Some(file),
+            });
+        }
+        let manifest = dir.join("Cargo.toml");
+        if !manifest.is_file() {
+            return Ok(Config::default());
+        }
+        match read(&manifest)?
+            .get("package")
+            .and_then(|package| package.get("metadata"))
+            .and_then(|metadata| metadata.get("enum_ids"))
+        {
+            Some(toml::Value::Table(table)) => Ok(Config {
+                defaults: get_defaults(table, METADATA)?,
+                file: None,
+            }),
+            Some(_) => Err(get_error(METADATA, "expecting a table")),
+            None => Ok(Config::default()),
+        }
+    }
+
+    /// Generates code, which makes the compiler rebuild the crate when `enum_ids.toml` changes.
+    pub fn track(&self) -> TokenStream {
+        let Some(file) = self.file.as_ref().and_then(|file| file.to_str()) else {
+            return quote! {};
+        };
+        quote! {
+            const _: &[u8] = include_bytes!(#file);
+        }
+    }
+}
+
+/// Reads and parses a TOML file.
+fn read(path: &Path) -> Result<toml::Table, syn::Error> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("Cannot read \"{}\": {e}", path.display()),
+        )
+    })?;
+    content.parse::<toml::Table>().map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("Cannot parse \"{}\": {e}", path.display()),
+        )
+    })
+}
+
+/// Converts the table of defaults into arguments of the macro and parses them.
+fn get_defaults(table: &toml::Table, source: &str) -> Result<Context, syn::Error> {
+    let mut args = Vec::new();
+    let mut errors = Vec::new();
+    for (key, value) in table {
+        if !is_ident(key) {
+            errors.push(get_error(
+                source,
+                &format!("\"{key}\" isn't a valid attribute"),
+            ));
+            continue;
+        }
+        let ident = get_ident(key, Span::call_site());
+        match value {
+            toml::Value::Boolean(true) => args.push(quote! { #ident }),
+            toml::Value::Boolean(false) => {}
+            toml::Value::String(value) => {
+                let value = LitStr::new(value, Span::call_site());
+                args.push(quote! { #ident = #value });
+            }
+            toml::Value::Array(items) => {
+                let items: Result<Vec<TokenStream>, _> = items
+                    .iter()
+                    .map(|item| item.as_str().and_then(|item| syn::parse_str(item).ok()))
+                    .map(|item| item.ok_or(()))
+                    .collect();
+                if let Ok(items) = items {
+                    args.push(quote! { #ident(#(#items),*) });
+                } else {
+                    errors.push(get_error(
+                        source,
+                        &format!("Attribute \"{key}\" expects an array of strings with tokens"),
+                    ));
+                }
+            }
+            _ => errors.push(get_error(
+                source,
+                &format!("Attribute \"{key}\" expects a string, a boolean or an array of strings"),
+            )),
+        }
+    }
+    let defaults = syn::parse2::<Context>(quote! { #(#args),* }).map_err(|err| {
+        for e in err {
+            errors.push(get_error(source, &e.to_string()));
+        }
+    });
+    combine_errors(errors)?;
+    Ok(defaults.unwrap_or_default())
+}
+
+/// Creates an error of the defaults; such an error is reported at the attribute of the enum,
+/// so the message names the source of the defaults.
+fn get_error(source: &str, message: &str) -> syn::Error {
+    syn::Error::new(Span::call_site(), format!("{source}: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, FILE};
+    use crate::{attr::Attr, context::Context};
+    use proc_macro2::Span;
+    use std::{env, fs, path::PathBuf};
+    use syn::Ident;
+
+    /// Creates a crate in a temporary directory with the given files.
+    fn create(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = env::temp_dir().join(format!("enum_ids_config_{}_{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("create directory of crate");
+        for (file, content) in files {
+            fs::write(dir.join(file), content).expect("write file of crate");
+        }
+        dir
+    }
+
+    /// Returns the names of attributes of the context.
+    fn names(cx: &Context) -> Vec<String> {
+        cx.attrs.iter().map(ToString::to_string).collect()
+    }
+
+    /// Parses the arguments of the macro and merges them with the defaults.
+    fn merge(args: &str, defaults: &str) -> Context {
+        syn::parse_str::<Context>(args)
+            .expect("arguments")
+            .with_defaults(syn::parse_str::<Context>(defaults).expect("defaults"))
+    }
+
+    const MANIFEST: &str = r#"
+[package]
+name = "fixture"
+version = "0.1.0"
+
+[package.metadata.enum_ids]
+getter = "meta"
+set = true
+"#;
+
+    #[test]
+    fn reads_file() {
+        let dir = create(
+            "file",
+            &[
+                (
+                    FILE,
+                    r#"
+derive = ["Debug", "Clone", "serde::Serialize"]
+display_variant_snake = true
+public = true
+iterator = false
+name = "{}Kind"
+getter = "kind"
+"#,
+                ),
+                ("Cargo.toml", MANIFEST),
+            ],
+        );
+        let config = Config::from_dir(&dir).expect("config");
+        assert_eq!(config.file, Some(dir.join(FILE)));
+        assert_eq!(
+            names(&config.defaults),
+            [
+                "derive",
+                "display_variant_snake",
+                "getter",
+                "name",
+                "public"
+            ]
+        );
+        let src = Ident::new("Shape", Span::call_site());
+        assert_eq!(config.defaults.enum_name(&src), "ShapeKind");
+        assert_eq!(config.defaults.getter_name(&src), "kind");
+        assert!(!config.track().is_empty());
+    }
+
+    #[test]
+    fn reads_metadata() {
+        let dir = create("metadata", &[("Cargo.toml", MANIFEST)]);
+        let config = Config::from_dir(&dir).expect("config");
+        assert_eq!(config.file, None);
+        assert_eq!(names(&config.defaults), ["getter", "set"]);
+        assert!(config.track().is_empty());
+    }
+
+    #[test]
+    fn no_config() {
+        let dir = create("none", &[("Cargo.toml", "[package]\nname = \"fixture\"\n")]);
+        assert!(Config::from_dir(&dir)
+            .expect("config")
+            .defaults
+            .attrs
+            .is_empty());
+    }
+
+    #[test]
+    fn malformed_config() {
+        let dir = create("malformed", &[(FILE, "derive = [\"Debug\"")]);
+        let err = Config::from_dir(&dir).err().expect("error");
+        assert!(err.to_string().starts_with("Cannot parse"));
+
+        let dir = create(
+            "invalid",
+            &[(FILE, "iterator = 1\nunknown = true\nrepr = \"u7\"\n")],
+        );
+        let errors: Vec<String> = Config::from_dir(&dir)
+            .err()
+            .expect("error")
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect();
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|e| e.starts_with("enum_ids.toml: ")));
+
+        let dir = create(
+            "metadata_value",
+            &[(
+                "Cargo.toml",
+                "[package]\nname = \"fixture\"\nmetadata.enum_ids = 1\n",
+            )],
+        );
+        let err = Config::from_dir(&dir).err().expect("error");
+        assert_eq!(
+            err.to_string(),
+            "[package.metadata.enum_ids]: expecting a table"
+        );
+    }
+
+    #[test]
+    fn attributes_of_enum_take_precedence() {
+        let cx = merge(
+            "getter = \"id\", not_public, display",
+            "getter = \"kind\", public, display_variant_snake, set",
+        );
+        assert_eq!(names(&cx), ["set", "getter", "not_public", "display"]);
+        assert!(matches!(&cx.attrs[1], Attr::Getter(name) if name == "id"));
+    }
+
+    #[test]
+    fn rename_all_overrides_display_variant_snake() {
+        let cx = merge(
+            "rename_all = \"kebab-case\"",
+            "display_variant_snake, public",
+        );
+        assert_eq!(names(&cx), ["display_variant", "public", "rename_all"]);
+        assert!(cx.validate(&[], &[]).is_ok());
+    }
+
+    #[test]
+    fn defaults_are_switched_off() {
+        let cx = merge(
+            "iterator = false, set = false, map = true",
+            "iterator, set, public",
+        );
+        assert_eq!(names(&cx), ["public", "map"]);
+    }
+
+    #[test]
+    fn lists_are_merged() {
+        let cx = merge(
+            "attrs(non_exhaustive), value(code: u8 = 1)",
+            "attrs(allow(dead_code)), value(level: u8 = 0)",
+        );
+        assert_eq!(names(&cx), ["attrs", "value", "attrs", "value"]);
+    }
+}
@@ -0,0 +1,1003 @@
+use crate::{
+    attr, case::Case, check_combinations, check_ident, combine_errors, get_ident, get_str_value,
+    is_ident, value, variant::Variant,
+};
+use proc_macro2::Span;
+use quote::ToTokens;
+use std::convert::TryFrom;
+use syn::{
+    parse::{self, Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    Attribute, Expr, ExprCall, ExprLit, Fields, Ident, Lit, LitStr, Meta, Path, Token, Visibility,
+};
+
+/// Represents the context for generating enum IDs, holding relevant attributes.
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    /// A list of attributes applied to the enum.
+    pub attrs: Vec<attr::Attr>,
+    /// The spans of the keys of attributes in the same order as `attrs`.
+    spans: Vec<Span>,
+}
+
+impl Context {
+    /// Creates a new `Context` with the provided attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `attrs` - A vector of `Attr` attributes together with the spans of their keys.
+    pub(self) fn new(attrs: Vec<(attr::Attr, Span)>) -> Self {
+        let (attrs, spans) = attrs.into_iter().unzip();
+        Self { attrs, spans }
+    }
+
+    /// Adds the defaults of the crate (see `config`), which aren't overridden by the attributes
+    /// of the enum.
+    ///
+    /// A default is dropped if the enum defines the same attribute, an attribute, which cannot
+    /// be used together with it (e.g. `not_public` overrides `public`), or switches it off with
+    /// `key = false`. `rename_all` of the enum turns the default `display_variant_snake` into
+    /// `display_variant`. Lists `attrs(...)` and `value(...)` are merged.
+    ///
+    /// # Arguments
+    ///
+    /// * `defaults` - The attributes read from the config.
+    ///
+    /// # Returns
+    ///
+    /// * The context with the defaults followed by the attributes of the enum.
+    pub fn with_defaults(self, defaults: Context) -> Self {
+        let names: Vec<String> = self.attrs.iter().map(ToString::to_string).collect();
+        let overridden = |name: &str| {
+            names.iter().any(|own| {
+                own == name
+                    || attr::CONFLICTS.iter().any(|(first, second)| {
+                        (first == own && *second == name) || (second == own && *first == name)
+                    })
+            })
+        };
+        let unset = |name: &str| {
+            self.attrs
+                .iter()
+                .any(|at| matches!(at, attr::Attr::Unset(unset) if unset == name))
+        };
+        let renamed = self
+            .attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::RenameAll(..)));
+        let mut attrs: Vec<(attr::Attr, Span)> = defaults
+            .attrs
+            .into_iter()
+            .zip(defaults.spans)
+            .filter_map(|(at, span)| {
+                let name = at.to_string();
+                if unset(&name) {
+                    None
+                } else if matches!(at, attr::Attr::Attrs(..) | attr::Attr::Value(..)) {
+                    Some((at, span))
+                } else if overridden(&name) {
+                    None
+                } else if renamed && matches!(at, attr::Attr::DisplayVariantSnake) {
+                    Some((attr::Attr::DisplayVariant, span))
+                } else {
+                    Some((at, span))
+                }
+            })
+            .collect();
+        attrs.extend(
+            self.attrs
+                .into_iter()
+                .zip(self.spans)
+                .filter(|(at, _)| !matches!(at, attr::Attr::Unset(..))),
+        );
+        Context::new(attrs)
+    }
+
+    /// Checks that the attributes can be used together and can be applied to the variants.
+    ///
+    /// Rejects attributes defined more than once (except lists `attrs(...)` and `value(...)`),
+    /// contradictory attributes like `public` with `not_public`, attributes, which cannot be
+    /// applied to the enum like `display_from_value` on an enum with unit variants, and attributes
+    /// of the generated enum, which clash with the generated code like derived `serde::Serialize`
+    /// with `serde` or `attrs(repr(u16))` with `repr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `variants` - All variants of the source enum.
+    /// * `attrs` - The attributes of the source enum.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` or an error with all found problems.
+    pub fn validate(&self, variants: &[Variant], attrs: &[Attribute]) -> Result<(), syn::Error> {
+        let names: Vec<String> = self.attrs.iter().map(ToString::to_string).collect();
+        let mut errors = check_combinations(&names, &self.spans, attr::LISTS, attr::CONFLICTS);
+        errors.extend(self.check_generated(attrs));
+        if let Some(i) = self
+            .attrs
+            .iter()
+            .position(|at| matches!(at, attr::Attr::RenameAll(case) if case != "snake_case"))
+        {
+            if self.display_variant_snake() {
+                errors.push(syn::Error::new(
+                    self.spans[i],
+                    "Attribute \"rename_all\" cannot be used together with \"display_variant_snake\", which uses \"snake_case\"",
+                ));
+            }
+        }
+        if let Some(i) = self
+            .attrs
+            .iter()
+            .position(|at| matches!(at, attr::Attr::SerdeAny))
+        {
+            if self.serde().is_none() {
+                errors.push(syn::Error::new(
+                    self.spans[i],
+                    "Attribute \"serde_any\" can be used only with \"serde\"",
+                ));
+            }
+        }
+        if let Some(i) = self
+            .attrs
+            .iter()
+            .position(|at| matches!(at, attr::Attr::IterAll(..)))
+        {
+            if !self.iter_required() {
+                errors.push(syn::Error::new(
+                    self.spans[i],
+                    "Attribute \"iter_all\" can be used only with \"iter\"",
+                ));
+            }
+        }
+        if self.display_from_value_required() {
+            for variant in variants {
+                if !matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+                {
+                    errors.push(syn::Error::new(
+                        variant.ident.span(),
+                        format!(
+                            "Attribute \"display_from_value\" requires a single unnamed field, but variant \"{}\" doesn't have it",
+                            variant.ident
+                        ),
+                    ));
+                }
+            }
+        }
+        combine_errors(errors)
+    }
+
+    /// Checks the derives and attributes of the generated enum against the implementations,
+    /// which are generated by `serde` and `repr`.
+    fn check_generated(&self, attrs: &[Attribute]) -> Vec<syn::Error> {
+        let mut errors = Vec::new();
+        if self.serde().is_some() {
+            for derive in self.derive(attrs) {
+                let Ok(traits) =
+                    derive.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                else {
+                    continue;
+                };
+                for path in traits.iter().filter(|path| {
+                    path.segments
+                        .last()
+                        .is_some_and(|s| s.ident == "Serialize" || s.ident == "Deserialize")
+                }) {
+                    errors.push(syn::Error::new_spanned(
+                        path,
+                        format!(
+                            "Trait \"{}\" is implemented by attribute \"serde\" and cannot be derived",
+                            path.to_token_stream().to_string().replace(' ', "")
+                        ),
+                    ));
+                }
+            }
+        }
+        if self
+            .attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::Repr(..)))
+        {
+            for forwarded in self.forwarded_attrs(attrs) {
+                if forwarded.path().is_ident("repr") {
+                    errors.push(syn::Error::new_spanned(
+                        forwarded.path(),
+                        "Attribute \"repr\" of the generated enum is set by attribute \"repr\" and cannot be added",
+                    ));
+                }
+            }
+        }
+        errors
+    }
+
+    /// Determines `display` is required
+    pub fn display_required(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::Display))
+    }
+
+    /// Determines `display_variant` is required
+    pub fn display_variant(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::DisplayVariant))
+    }
+
+    /// Determines `display_variant_snake` is required
+    pub fn display_variant_snake(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::DisplayVariantSnake))
+    }
+
+    /// Determines `iterator` is required
+    pub fn iterator(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::Iterator))
+    }
+
+    /// Determines parsing from string is required (`parse` or `parse_ignore_case`)
+    pub fn parse_required(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::Parse | attr::Attr::ParseIgnoreCase))
+    }
+
+    /// Determines `parse_ignore_case` is required
+    pub fn parse_ignore_case(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::ParseIgnoreCase))
+    }
+
+    /// Determines `predicates` is required
+    pub fn predicates(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::Predicates))
+    }
+
+    /// Determines `accessors` is required
+    pub fn accessors(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::Accessors))
+    }
+
+    /// Determines `set` is required
+    pub fn set(&self) -> bool {
+        self.attrs.iter().any(|at| matches!(at, attr::Attr::Set))
+    }
+
+    /// Determines `ordinal` is required
+    pub fn ordinal(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::Ordinal))
+    }
+
+    /// Determines `iter` is required
+    pub fn iter_required(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::Iter(..)))
+    }
+
+    /// Determines `map` is required
+    pub fn map(&self) -> bool {
+        self.attrs.iter().any(|at| matches!(at, attr::Attr::Map))
+    }
+
+    /// Determines the format of `Serialize` and `Deserialize` for the generated ID enum
+    /// (`name` or `index`), if `serde` is required.
+    pub fn serde(&self) -> Option<&str> {
+        self.attrs.iter().find_map(|at| {
+            if let attr::Attr::Serde(format) = at {
+                Some(format.as_str())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Determines `serde_any` is required
+    pub fn serde_any(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::SerdeAny))
+    }
+
+    /// Determines `panic_on_skip` is required
+    pub fn panic_on_skip(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::PanicOnSkip))
+    }
+
+    /// Determines `display_from_value` is required
+    pub fn display_from_value_required(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::DisplayFromValue))
+    }
+
+    /// Determines the case, which names of variants are converted into.
+    ///
+    /// - If a `RenameAll` attribute is present, its case is used.
+    /// - If `display_variant_snake` is used as the display mode, snake case is used.
+    /// - Otherwise, names of variants aren't converted.
+    pub fn rename_all(&self) -> Option<Case> {
+        self.attrs
+            .iter()
+            .find_map(|at| {
+                if let attr::Attr::RenameAll(case) = at {
+                    Case::try_from(case.as_str()).ok()
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                if self.display_variant_snake()
+                    && !self.display_variant()
+                    && !self.display_required()
+                {
+                    Some(Case::Snake)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Returns the values declared for the enum with `value(...)`.
+    pub fn values(&self) -> impl Iterator<Item = &value::Value> {
+        self.attrs.iter().flat_map(|at| {
+            if let attr::Attr::Value(values) = at {
+                values.as_slice()
+            } else {
+                &[]
+            }
+        })
+    }
+
+    /// Determines the name of the generated ID enum.
+    ///
+    /// If an `EnumName` attribute is present, its value is used; `{}` in the value is replaced
+    /// by the name of the source enum (`name = "{}Kind"`).
+    /// Otherwise, the default naming convention (`ParentNameId`) is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The identifier of the source enum.
+    ///
+    /// # Returns
+    ///
+    /// * An `Ident` representing the name of the generated ID enum.
+    pub fn enum_name(&self, src: &Ident) -> Ident {
+        let name = self
+            .attrs
+            .iter()
+            .find_map(|at| {
+                if let attr::Attr::EnumName(name) = at {
+                    Some(name.to_owned())
+                } else {
+                    None
+                }
+            })
+            .map_or(format!("{src}Id"), |name| {
+                name.replace("{}", &src.to_string())
+            });
+        get_ident(&name, src.span())
+    }
+
+    /// Determines the doc comment of the generated ID enum.
+    ///
+    /// If a `Doc` attribute is present, its value is used.
+    /// Otherwise, the default doc comment referring to the source enum is applied.
+    /// The doc comments of other generated types don't depend on the attribute.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The identifier of the source enum.
+    ///
+    /// # Returns
+    ///
+    /// * A `String` with the doc comment.
+    pub fn doc(&self, src: &Ident) -> String {
+        self.attrs
+            .iter()
+            .find_map(|at| {
+                if let attr::Attr::Doc(doc) = at {
+                    Some(doc.to_owned())
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| format!(" IDs of variants of `{src}`."))
+    }
+
+    /// Determines the name of the getter method for the ID.
+    ///
+    /// If a `Getter` attribute is present, its value is used.
+    /// Otherwise, the default method name `id` is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The identifier of the source enum.
+    ///
+    /// # Returns
+    ///
+    /// * An `Ident` representing the name of the getter method.
+    pub fn getter_name(&self, src: &Ident) -> Ident {
+        let name = self
+            .attrs
+            .iter()
+            .find_map(|at| {
+                if let attr::Attr::Getter(name) = at {
+                    Some(name.to_owned())
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(String::from("id"));
+        get_ident(&name, src.span())
+    }
+
+    /// Determines the name of the method of the generated ID enum, which returns the iterator over all IDs.
+    ///
+    /// If an `Iter` attribute with a name is present, its value is used.
+    /// Otherwise, the default method name `iter` is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ident` - The identifier of the generated ID enum.
+    ///
+    /// # Returns
+    ///
+    /// * An `Ident` representing the name of the method.
+    pub fn iter_name(&self, dest_ident: &Ident) -> Ident {
+        let name = self
+            .attrs
+            .iter()
+            .find_map(|at| {
+                if let attr::Attr::Iter(name) = at {
+                    name.to_owned()
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(String::from("iter"));
+        get_ident(&name, dest_ident.span())
+    }
+
+    /// Determines the name of the type, which implements `IntoIterator` over all IDs.
+    ///
+    /// If an `IterAll` attribute is present, its value is used.
+    /// Otherwise, the name of the generated ID enum with the suffix `s` is applied (`KindIds`).
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ident` - The identifier of the generated ID enum.
+    ///
+    /// # Returns
+    ///
+    /// * An `Ident` representing the name of the type.
+    pub fn iter_all_name(&self, dest_ident: &Ident) -> Ident {
+        let name = self
+            .attrs
+            .iter()
+            .find_map(|at| {
+                if let attr::Attr::IterAll(name) = at {
+                    Some(name.to_owned())
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| format!("{dest_ident}s"));
+        get_ident(&name, dest_ident.span())
+    }
+
+    /// Determines the integer representation of the generated ID enum.
+    ///
+    /// - If a `Repr` attribute with a type is present, this type is used.
+    /// - If a `Repr` attribute without a type is present, the smallest unsigned type,
+    ///   which can hold all variants, is used.
+    /// - Otherwise, the generated ID enum doesn't have an integer representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of variants of the generated ID enum.
+    ///
+    /// # Returns
+    ///
+    /// * An optional `Ident` with the name of the integer type.
+    pub fn repr(&self, count: usize) -> Option<Ident> {
+        self.attrs.iter().find_map(|at| {
+            if let attr::Attr::Repr(ty) = at {
+                let ty = ty.to_owned().unwrap_or_else(|| {
+                    String::from(if count <= 1 << 8 {
+                        "u8"
+                    } else if count <= 1 << 16 {
+                        "u16"
+                    } else {
+                        "u32"
+                    })
+                });
+                Some(Ident::new(&ty, Span::call_site()))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Determines the visibility of the generated ID enum.
+    ///
+    /// - If the `Public` attribute is present, the enum is made public.
+    /// - If the `NotPublic` attribute is present, the enum is made private.
+    /// - Otherwise, the visibility is inherited from the source enum.
+    ///
+    /// # Arguments
+    ///
+    /// * `vis` - The visibility of the source enum.
+    ///
+    /// # Returns
+    ///
+    /// * A `Visibility` instance representing the desired visibility.
+    pub fn visibility(&self, vis: &Visibility) -> Visibility {
+        if self.attrs.iter().any(|at| matches!(at, attr::Attr::Public)) {
+            Visibility::Public(syn::token::Pub(proc_macro2::Span::call_site()))
+        } else if self
+            .attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::NotPublic))
+        {
+            Visibility::Inherited
+        } else {
+            vis.clone()
+        }
+    }
+
+    /// Determines the derive attributes for the generated ID enum.
+    ///
+    /// - If the `NoDerive` attribute is present, no derive attributes are added.
+    /// - If a `Derive` attribute is present, only the specified traits are derived.
+    /// - Otherwise, inherits derive attributes from the source enum.
+    ///
+    /// # Arguments
+    ///
+    /// * `attrs` - A slice of attributes from the source enum.
+    ///
+    /// # Returns
+    ///
+    /// * A vector of `Attribute` instances to be applied to the generated enum.
+    pub fn derive(&self, attrs: &[Attribute]) -> Vec<Attribute> {
+        if self
+            .attrs
+            .iter()
+            .any(|at| matches!(at, attr::Attr::NoDerive))
+        {
+            vec![]
+        } else if let Some(attr::Attr::Derive(traits)) = self
+            .attrs
+            .iter()
+            .find(|at| matches!(at, attr::Attr::Derive(..)))
+        {
+            vec![parse_quote! { #[derive(#traits)] }]
+        } else {
+            attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("derive"))
+                .cloned()
+                .collect()
+        }
+    }
+
+    /// Determines the attributes, which are added to the generated ID enum in addition to derives.
+    ///
+    /// - Attributes listed in `attrs(...)` are added as they are.
+    /// - Attributes of the source enum with names listed in `inherit_attrs` are copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `attrs` - A slice of attributes from the source enum.
+    ///
+    /// # Returns
+    ///
+    /// * A vector of `Attribute` instances to be applied to the generated enum.
+    pub fn forwarded_attrs(&self, attrs: &[Attribute]) -> Vec<Attribute> {
+        let mut forwarded: Vec<Attribute> = Vec::new();
+        for at in &self.attrs {
+            match at {
+                attr::Attr::InheritAttrs(list) => {
+                    let names: Vec<&str> = list.split(',').map(str::trim).collect();
+                    forwarded.extend(
+                        attrs
+                            .iter()
+                            .filter(|attr| {
+                                attr.path().get_ident().is_some_and(|ident| {
+                                    names.contains(&ident.to_string().as_str())
+                                })
+                            })
+                            .cloned(),
+                    );
+                }
+                attr::Attr::Attrs(list) => {
+                    forwarded.extend(list.iter().map(|tokens| parse_quote! { #[#tokens] }));
+                }
+                _ => {}
+            }
+        }
+        forwarded
+    }
+
+    /// Collects the attributes listed in `attrs(...)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments of `attrs(...)`.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` containing the attribute or a parsing error, if an argument isn't a valid attribute.
+    fn with_attrs(args: &Punctuated<Expr, Token![,]>) -> parse::Result<attr::Attr> {
+        let mut list = Vec::new();
+        for arg in args {
+            let meta: Meta = syn::parse2(arg.to_token_stream()).map_err(|_| {
+                syn::Error::new_spanned(
+                    arg,
+                    "Expecting attribute like name, name(...) or name = value",
+                )
+            })?;
+            if meta.path().is_ident("derive") {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "Use attribute \"derive\" to set derives of the generated enum",
+                ));
+            }
+            list.push(meta.to_token_stream());
+        }
+        Ok(attr::Attr::Attrs(list))
+    }
+
+    /// Recognizes a standalone attribute like `public` or `set`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ident` - The name of the attribute.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` containing the attribute or a parsing error, if the attribute requires a value.
+    fn with_flag(attr: attr::Attr, ident: &Ident) -> parse::Result<attr::Attr> {
+        match attr {
+            attr::Attr::NoDerive
+            | attr::Attr::NotPublic
+            | attr::Attr::Public
+            | attr::Attr::Display
+            | attr::Attr::DisplayVariant
+            | attr::Attr::DisplayVariantSnake
+            | attr::Attr::Iterator
+            | attr::Attr::Parse
+            | attr::Attr::ParseIgnoreCase
+            | attr::Attr::DisplayFromValue
+            | attr::Attr::PanicOnSkip
+            | attr::Attr::Predicates
+            | attr::Attr::Accessors
+            | attr::Attr::Set
+            | attr::Attr::Map
+            | attr::Attr::Ordinal
+            | attr::Attr::Iter(None)
+            | attr::Attr::SerdeAny
+            | attr::Attr::Repr(None) => Ok(attr),
+            attr::Attr::Value(..) => Err(value::get_value_error(ident.span())),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!("Attribute \"{ident}\" cannot be applied at this level"),
+            )),
+        }
+    }
+
+    /// Sets the value of an attribute, which is defined as `key = "value"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attr` - The attribute recognized by key.
+    /// * `left` - The key of the attribute.
+    /// * `value` - The value of the attribute.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` containing the attribute with the value or a parsing error.
+    fn with_value(attr: &attr::Attr, left: &Ident, value: &LitStr) -> parse::Result<attr::Attr> {
+        Ok(match attr {
+            attr::Attr::Derive(..) => attr::Attr::Derive(
+                value
+                    .parse_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                    .map_err(|_| {
+                        syn::Error::new(
+                            value.span(),
+                            "Expecting comma-separated list of traits like \"Debug, serde::Serialize\"",
+                        )
+                    })?,
+            ),
+            attr::Attr::Getter(..) => {
+                check_ident(value)?;
+                attr::Attr::Getter(value.value())
+            }
+            attr::Attr::Iter(..) => {
+                check_ident(value)?;
+                attr::Attr::Iter(Some(value.value()))
+            }
+            attr::Attr::IterAll(..) => {
+                check_ident(value)?;
+                attr::Attr::IterAll(value.value())
+            }
+            attr::Attr::EnumName(..) => {
+                if !is_ident(&value.value().replace("{}", "Enum")) {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        format!("\"{}\" isn't a valid identifier", value.value()),
+                    ));
+                }
+                attr::Attr::EnumName(value.value())
+            }
+            attr::Attr::Doc(..) => attr::Attr::Doc(value.value()),
+            attr::Attr::RenameAll(..) => {
+                Case::try_from(value.value().as_str())
+                    .map_err(|e| syn::Error::new(value.span(), e))?;
+                attr::Attr::RenameAll(value.value())
+            }
+            attr::Attr::InheritAttrs(..) => {
+                for name in value.value().split(',').map(str::trim) {
+                    if syn::parse_str::<Ident>(name).is_err() {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            format!("\"{name}\" isn't a valid name of attribute"),
+                        ));
+                    }
+                    if name == "derive" {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "Derives are inherited by default; use \"derive\" or \"no_derive\" to change them",
+                        ));
+                    }
+                }
+                attr::Attr::InheritAttrs(value.value())
+            }
+            attr::Attr::Serde(..) => {
+                if !cfg!(feature = "serde") {
+                    return Err(syn::Error::new(
+                        left.span(),
+                        "Attribute \"serde\" requires feature \"serde\" of enum_ids",
+                    ));
+                }
+                if !attr::SERDE_FORMATS.contains(&value.value().as_str()) {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        format!(
+                            "Expecting one of formats: {}",
+                            attr::SERDE_FORMATS.join(", ")
+                        ),
+                    ));
+                }
+                attr::Attr::Serde(value.value())
+            }
+            attr::Attr::Repr(..) => {
+                if !attr::REPR_TYPES.contains(&value.value().as_str()) {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        format!(
+                            "Expecting one of integer types: {}",
+                            attr::REPR_TYPES.join(", ")
+                        ),
+                    ));
+                }
+                attr::Attr::Repr(Some(value.value()))
+            }
+            attr::Attr::Value(..) => return Err(value::get_value_error(left.span())),
+            _ => {
+                return Err(syn::Error::new(
+                    left.span(),
+                    format!("Attribute \"{left}\" cannot be applied at this level"),
+                ));
+            }
+        })
+    }
+
+    /// Parses the arguments of the macro (see `Parse` for `Context`) keeping going after an
+    /// invalid argument, so that all errors can be reported at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input stream of tokens.
+    ///
+    /// # Returns
+    ///
+    /// * The context with the valid arguments and the errors of the invalid ones, or an error
+    ///   if the arguments cannot be split.
+    pub fn parse_args(input: ParseStream) -> parse::Result<(Self, Vec<syn::Error>)> {
+        let mut attrs: Vec<(attr::Attr, Span)> = vec![];
+        let mut errors = Vec::new();
+        for arg in Punctuated::<value::Arg, Token![,]>::parse_terminated(input)? {
+            match Context::parse_arg(arg) {
+                Ok(parsed) => attrs.extend(parsed),
+                Err(error) => errors.push(error),
+            }
+        }
+        Ok((Context::new(attrs), errors))
+    }
+
+    /// Parses a single argument of the macro into attributes and the spans of their keys.
+    ///
+    /// Lists like `derive(Debug, Clone)` and groups like `display(variant, rename_all = snake_case)`
+    /// give several attributes.
+    fn parse_arg(arg: value::Arg) -> parse::Result<Vec<(attr::Attr, Span)>> {
+        match arg {
+            value::Arg::Values(ident, values) => {
+                Ok(vec![(attr::Attr::Value(values), ident.span())])
+            }
+            value::Arg::Expr(Expr::Call(call)) => Context::parse_list(&call),
+            value::Arg::Expr(expr) => Ok(vec![Context::parse_option(expr, None)?]),
+        }
+    }
+
+    /// Parses a list like `derive(Debug, serde::Serialize)`, `attrs(...)` or a group of options
+    /// like `display(variant, rename_all = "snake_case")`.
+    ///
+    /// A group accepts only its own options (see `attr::GROUPS`), which are looked up with the name
+    /// of the group as a prefix first (`variant` in `display(...)` is `display_variant`), then as
+    /// they are. The group itself is enabled unless one of its options is prefixed, so `parse()`
+    /// is the same as `parse`.
+    fn parse_list(call: &ExprCall) -> parse::Result<Vec<(attr::Attr, Span)>> {
+        let Expr::Path(func) = call.func.as_ref() else {
+            return Err(syn::Error::new_spanned(
+                &call.func,
+                "Cannot extract identifier",
+            ));
+        };
+        let Some(ident) = func.path.get_ident() else {
+            return Err(syn::Error::new_spanned(func, "Cannot extract identifier"));
+        };
+        let attr = Context::get_attr(ident, None)?;
+        match attr {
+            attr::Attr::Attrs(..) => Ok(vec![(Context::with_attrs(&call.args)?, ident.span())]),
+            attr::Attr::Derive(..) => {
+                let mut traits = Punctuated::<Path, Token![,]>::new();
+                for arg in &call.args {
+                    match arg {
+                        Expr::Path(p) if p.qself.is_none() => traits.push(p.path.clone()),
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                arg,
+                                "Expecting path of trait like Debug or serde::Serialize",
+                            ));
+                        }
+                    }
+                }
+                Ok(vec![(attr::Attr::Derive(traits), ident.span())])
+            }
+            attr::Attr::Display | attr::Attr::Parse => {
+                let prefix = format!("{ident}_");
+                let mut attrs = Vec::new();
+                let mut errors = Vec::new();
+                for arg in &call.args {
+                    match Context::parse_option(arg.clone(), Some(ident)) {
+                        Ok(option) => attrs.push(option),
+                        Err(error) => errors.push(error),
+                    }
+                }
+                combine_errors(errors)?;
+                if !attrs
+                    .iter()
+                    .any(|(at, _)| at.to_string().starts_with(&prefix))
+                {
+                    attrs.insert(0, (attr, ident.span()));
+                }
+                Ok(attrs)
+            }
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!("Attribute \"{ident}\" cannot be used as a list"),
+            )),
+        }
+    }
+
+    /// Parses an option like `key`, `key = "value"` or `key = value` optionally within a group.
+    fn parse_option(expr: Expr, group: Option<&Ident>) -> parse::Result<(attr::Attr, Span)> {
+        match expr {
+            Expr::Assign(a) => {
+                let Expr::Path(left) = a.left.as_ref() else {
+                    return Err(syn::Error::new(
+                        a.eq_token.span,
+                        "Expecting expression like key = \"value as String\" or key = value",
+                    ));
+                };
+                let Some(left) = left.path.get_ident() else {
+                    return Err(syn::Error::new_spanned(left, "Cannot extract identifier"));
+                };
+                let attr = Context::get_attr(left, group)?;
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Bool(flag),
+                    ..
+                }) = a.right.as_ref()
+                {
+                    return if flag.value {
+                        Ok((Context::with_flag(attr, left)?, left.span()))
+                    } else {
+                        Ok((attr::Attr::Unset(attr.to_string()), left.span()))
+                    };
+                }
+                let Some(value) = get_str_value(&a.right) else {
+                    return Err(syn::Error::new(
+                        a.eq_token.span,
+                        "Expecting expression like key = \"value as String\" or key = value",
+                    ));
+                };
+                Ok((Context::with_value(&attr, left, &value)?, left.span()))
+            }
+            Expr::Path(p) => {
+                let Some(ident) = p.path.get_ident() else {
+                    return Err(syn::Error::new_spanned(p, "Cannot extract identifier"));
+                };
+                let attr = Context::get_attr(ident, group)?;
+                Ok((Context::with_flag(attr, ident)?, ident.span()))
+            }
+            _ => Err(syn::Error::new_spanned(
+                expr,
+                "Expecting expression like [key = \"value as String\"] or [key]",
+            )),
+        }
+    }
+
+    /// Recognizes the attribute by its key. Within a group only the options of the group are
+    /// accepted and the key prefixed with the name of the group is tried first.
+    fn get_attr(key: &Ident, group: Option<&Ident>) -> parse::Result<attr::Attr> {
+        if let Some(group) = group {
+            let options = attr::GROUPS
+                .iter()
+                .find_map(|(name, options)| group.eq(name).then_some(*options))
+                .unwrap_or_default();
+            if !options.iter().any(|option| key == option) {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "Option \"{key}\" doesn't belong to \"{group}(...)\"; expecting one of: {}",
+                        options.join(", ")
+                    ),
+                ));
+            }
+            if let Ok(attr) = attr::Attr::try_from(format!("{group}_{key}").as_ref()) {
+                return Ok(attr);
+            }
+        }
+        attr::Attr::try_from(key.to_string().as_ref()).map_err(|e| {
+            syn::Error::new(key.span(), format!("Cannot parse attribute \"{key}\": {e}"))
+        })
+    }
+}
+
+impl Parse for Context {
+    /// Parses a stream of tokens into a `Context` struct.
+    ///
+    /// The expected input can include:
+    /// - Attributes in the form of `key = "value"` or `key = value`
+    /// - Standalone attributes like `public`, `not_public`, `no_derive`
+    /// - Lists of attributes like `attrs(non_exhaustive, allow(dead_code))`
+    /// - Lists of traits like `derive(Debug, serde::Serialize)`
+    /// - Groups of options like `display(variant, rename_all = "snake_case")`
+    /// - Declarations of values like `value(status: u16 = 500)`
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input stream of tokens.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` containing the parsed `Context` or a parsing error.
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let (context, errors) = Context::parse_args(input)?;
+        combine_errors(errors)?;
+        Ok(context)
+    }
+}
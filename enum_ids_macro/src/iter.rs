@@ -0,0 +1,137 @@
+use crate::{context::Context, get_indexes, variant::Variant};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Visibility};
+
+/// Generates `KindIdIter`, an iterator over all variants of the generated ID enum, which doesn't
+/// allocate, with `iter` or `ordinal` (its `range()` returns the iterator). With `iter` also
+/// generates the method of the generated ID enum returning it (`KindId::iter()` by default) and
+/// `KindIds`, a zero-sized handle of all variants, which implements `IntoIterator`.
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - The variants of the generated ID enum.
+/// * `dest_ident` - The name of the generated ID enum.
+/// * `visibility` - The visibility of the generated ID enum.
+///
+/// # Returns
+///
+/// * Generated code.
+pub fn get_iter_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &Ident,
+    visibility: &Visibility,
+) -> TokenStream {
+    if !cx.iter_required() && !cx.ordinal() {
+        return quote! {};
+    }
+    let iter_struct = get_iter_ident(dest_ident);
+    let ids: Vec<Ident> = variants.iter().map(Variant::id_ident).collect();
+    let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+    let indexes = get_indexes(variants, &format_ident!("usize"));
+    let handles = if cx.iter_required() {
+        get_handles(cx, dest_ident, &iter_struct, visibility)
+    } else {
+        quote! {}
+    };
+    quote! {
+        #handles
+
+        /// Iterator over all IDs in the order of declaration.
+        #[derive(Clone, Debug)]
+        #visibility struct #iter_struct {
+            front: usize,
+            back: usize,
+        }
+
+        impl #iter_struct {
+            fn id(index: usize) -> #dest_ident {
+                match index {
+                    #(
+                        #(#cfgs)*
+                        index if index == #indexes => #dest_ident::#ids,
+                    )*
+                    _ => unreachable!("Invalid index of ID"),
+                }
+            }
+        }
+
+        impl Iterator for #iter_struct {
+            type Item = #dest_ident;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front == self.back {
+                    return None;
+                }
+                self.front += 1;
+                Some(Self::id(self.front - 1))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (len, Some(len))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.front = self.front.saturating_add(n).min(self.back);
+                self.next()
+            }
+        }
+
+        impl DoubleEndedIterator for #iter_struct {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front == self.back {
+                    return None;
+                }
+                self.back -= 1;
+                Some(Self::id(self.back))
+            }
+        }
+
+        impl ExactSizeIterator for #iter_struct {}
+
+        impl std::iter::FusedIterator for #iter_struct {}
+    }
+}
+
+/// Generates the method of the generated ID enum returning the iterator and the type `KindIds`.
+fn get_handles(
+    cx: &Context,
+    dest_ident: &Ident,
+    iter_struct: &Ident,
+    visibility: &Visibility,
+) -> TokenStream {
+    let iter_ident = cx.iter_name(dest_ident);
+    let all_struct = cx.iter_all_name(dest_ident);
+    quote! {
+        impl #dest_ident {
+            /// Returns an iterator over all variants in the order of declaration.
+            pub const fn #iter_ident() -> #iter_struct {
+                #iter_struct {
+                    front: 0,
+                    back: #dest_ident::COUNT,
+                }
+            }
+        }
+
+        /// All IDs, which can be iterated in the order of declaration without allocation.
+        #[derive(Clone, Copy, Debug, Default)]
+        #visibility struct #all_struct;
+
+        impl IntoIterator for #all_struct {
+            type Item = #dest_ident;
+            type IntoIter = #iter_struct;
+
+            fn into_iter(self) -> Self::IntoIter {
+                #dest_ident::#iter_ident()
+            }
+        }
+    }
+}
+
+/// Returns the name of the iterator over IDs, e.g. `KindIdIter`.
+pub fn get_iter_ident(dest_ident: &Ident) -> Ident {
+    format_ident!("{}Iter", dest_ident)
+}
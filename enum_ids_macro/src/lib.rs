@@ -0,0 +1,1125 @@
+#![warn(clippy::all, clippy::pedantic, clippy::cargo)]
+
+mod accessor;
+mod attr;
+mod case;
+mod config;
+mod context;
+mod iter;
+mod map;
+mod nested;
+mod ordinal;
+mod predicate;
+mod serde;
+mod set;
+mod value;
+mod variant;
+
+use config::Config;
+use context::Context;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{parse::Parser, parse_macro_input, spanned::Spanned, Attribute, Fields, ItemEnum};
+use variant::Variant;
+
+/// Procedural macro to generate a companion ID enum and an associated getter method for the annotated enum.
+///
+/// # Attributes
+///
+/// - `derive = "Trait1, Trait2, ..."`: Specifies traits to derive for the generated ID enum.
+/// - `getter = "method_name"`: Sets a custom name for the getter method instead of the default `id`.
+/// - `iter`: Adds method `iter()` returning `KindIdIter`, an iterator over all IDs without allocation, and
+///   type `KindIds`, which implements `IntoIterator` over all IDs.
+/// - `iter = "method_name"`: Same as `iter`, but sets a custom name of the method instead of `iter`.
+/// - `iter_all = "TypeName"`: Together with `iter`, sets a custom name of the type instead of `KindIds`.
+/// - `name = "CustomName"`: Sets a custom name for the generated ID enum instead of the default `ParentNameId`.
+/// - `doc = "..."`: Sets the doc comment of the generated ID enum instead of the default one. Other generated
+///   types (iterator, path, set, map) keep their default doc comments.
+/// - `public`: Makes the generated ID enum public.
+/// - `not_public`: Makes the generated ID enum private.
+/// - `no_derive`: Disables deriving traits for the generated ID enum.
+/// - `attrs(attr1, attr2(..), ...)`: Adds the attributes to the generated ID enum as they are,
+///   e.g. `attrs(non_exhaustive, cfg_attr(feature = "serde", derive(Serialize)))`.
+/// - `inherit_attrs = "serde, allow"`: Copies the attributes with the given names from the source enum
+///   to the generated ID enum.
+/// - `panic_on_skip`: The getter panics on variants marked with `#[enum_ids(skip)]` instead of returning `Option`.
+/// - `display`: Implements `std::fmt::Display` for the generated ID enum (`Kind :: A`).
+/// - `display_variant`: Implements `std::fmt::Display` for the generated ID enum using only variant's name (`A`).
+/// - `display_variant_snake`: Same as `display_variant`, but converts variant's name into snake case.
+/// - `rename_all = "snake_case"`: Converts names of variants, which are used by display and parse implementations,
+///   into the case: `snake_case`, `kebab-case`, `SCREAMING_SNAKE_CASE`, `SCREAMING-KEBAB-CASE`, `camelCase`,
+///   `PascalCase`, `lowercase`, `UPPERCASE` or `Title Case`.
+/// - `display_from_value`: Implements `std::fmt::Display` for the source enum from the value of a variant.
+/// - `iterator`: Adds method `as_vec()` to the source enum. Fields of variants are created with `Default`
+///   or with the expression of `#[enum_ids(sample = ...)]`.
+/// - `parse`: Implements `FromStr`, `TryFrom<&str>` and `TryFrom<String>` for the generated ID enum.
+/// - `parse_ignore_case`: Same as `parse`, but ignores ASCII case of the input.
+/// - `repr = "u8"`: Adds `#[repr(u8)]` to the generated ID enum, method `as_u8()` and conversions from/into `u8`.
+/// - `repr`: Same as `repr = "..."`, but the smallest unsigned type, which holds all variants, is used.
+/// - `predicates`: Adds predicates `is_a()`, `is_b()` etc. (names of variants in snake case) and
+///   `is_one_of(&[KindId])` to the source enum. With `set` adds `is_in_set(&KindIdSet)` as well.
+/// - `accessors`: Adds accessors of payloads `as_a()`, `as_a_mut()` (returning `Option`) and `into_a()`
+///   (returning `Result<_, Self>`) to the source enum for every variant with fields.
+/// - `set`: Adds type `KindIdSet` (a set of IDs backed by a bit mask) and macro `kind_id_set![..]`.
+///   The macro is local to the module of the enum and expects `KindIdSet` and `KindId` in scope.
+/// - `map`: Adds type `KindIdMap<V>` (a map with a value for every ID backed by an array).
+///   With feature `serde` the map implements `Serialize` and `Deserialize`.
+/// - `ordinal`: Adds navigation of the generated ID enum in the order of declaration: `index()`,
+///   `from_index()`, `next()`, `prev()`, `next_cyclic()`, `prev_cyclic()` and `range(..)`.
+///   The methods take the ID by value, so the generated enum should derive `Copy`.
+/// - `serde = "name"`: Implements `Serialize` and `Deserialize` for the generated ID enum using the strings
+///   of the active display mode. Requires feature `serde`.
+/// - `serde = "index"`: Same as `serde = "name"`, but variants are written as their positions in the enum
+///   (with the type of `repr` or `u64`).
+/// - `serde_any`: The deserializer of the generated ID enum accepts both names and indexes.
+/// - `value(status: u16 = 500)`: Declares typed values of variants. Adds `const fn status(&self) -> u16`
+///   to both enums; the expression is the default value and can be omitted.
+///
+/// # Attributes of variants
+///
+/// - `rename = "..."`: Sets the string, which is used for the variant by display and parse implementations.
+/// - `rename_id = "NewIdent"`: Sets the name of the variant in the generated ID enum.
+/// - `skip`: Excludes the variant from the generated ID enum.
+/// - `nested`: Marks the variant, which wraps another enum with IDs (`Kind::Net(NetKind)`). Adds type
+///   `KindPath` with the IDs of both levels (`KindPath::Net(NetKindId)`) and getter `deep_id()`.
+/// - `sample = expr`: Sets the expression, which creates the variant for `iterator` instead of
+///   creating its fields with `Default`.
+/// - `value(status = 404)`: Sets typed values of the variant. Values without a default must be
+///   set for every variant. A type can be given here as well (`value(status: u16 = 404)`).
+///
+/// # Generated items
+///
+/// The generated ID enum has constants `COUNT` (number of variants), `ALL` (all variants) and `NAMES`
+/// (names of all variants) and method `as_vec()`, which returns all variants as `Vec`.
+///
+/// # Traits
+///
+/// The generated ID enum implements `enum_ids::IdEnum`. The source enum implements `enum_ids::HasId`
+/// if the generated ID enum is at least as visible as the source enum.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_ids::enum_ids;
+///
+/// #[enum_ids]
+/// #[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+/// pub enum Kind {
+///     A(i32),
+///     B { value: String },
+///     C,
+/// }
+/// ```
+/// or with attributes
+///
+/// ```rust
+/// use enum_ids::enum_ids;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[enum_ids(getter = "get_id", derive = "Deserialize, Serialize", public)]
+/// #[derive(Debug, Clone)]
+/// pub enum Kind {
+///     A(i32),
+///     B { value: String },
+///     C,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn enum_ids(args: TokenStream, item: TokenStream) -> TokenStream {
+    let (context, mut errors) = match Context::parse_args.parse(args) {
+        Ok(parsed) => parsed,
+        Err(err) => return TokenStream::from(err.into_compile_error()),
+    };
+    let input: ItemEnum = parse_macro_input!(item as ItemEnum);
+    let config = Config::load().unwrap_or_else(|err| {
+        errors.push(err);
+        Config::default()
+    });
+    let track = config.track();
+    let context = context.with_defaults(config.defaults);
+    TokenStream::from(expand(&context, input, errors).map_or_else(
+        syn::Error::into_compile_error,
+        |output| {
+            quote! { #output #track }
+        },
+    ))
+}
+
+/// Generates the source enum and all generated items.
+///
+/// # Arguments
+///
+/// * `context` - The context of the macro.
+/// * `input` - The source enum.
+/// * `errors` - The errors found while parsing the arguments of the macro.
+///
+/// # Returns
+///
+/// * Generated code or all errors, if the source enum or attributes cannot be processed.
+fn expand(
+    context: &Context,
+    mut input: ItemEnum,
+    mut errors: Vec<syn::Error>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let variants: Vec<Variant> = Variant::collect(&mut input, &mut errors);
+    errors.extend(context.validate(&variants, &input.attrs).err());
+    errors.extend(check_not_empty(&input, &variants).err());
+    combine_errors(errors)?;
+
+    let src = &input.ident;
+    let visibility = context.visibility(&input.vis);
+    let dest_ident = context.enum_name(src);
+    let getter_ident = context.getter_name(src);
+
+    let ids: Vec<Variant> = variants.iter().filter(|v| !v.skip()).cloned().collect();
+
+    check_names(context, &ids)?;
+
+    let optional = ids.len() != variants.len() && !context.panic_on_skip();
+
+    let getter_output = if optional {
+        quote! { Option<#dest_ident> }
+    } else {
+        quote! { #dest_ident }
+    };
+
+    let derive_attrs: Vec<Attribute> = context.derive(&input.attrs);
+
+    let getter_impl = get_getter_impl(
+        &variants,
+        &dest_ident,
+        &getter_ident,
+        &getter_output,
+        optional,
+        &input,
+    );
+
+    let meta_impl = get_meta_impl(context, &ids, &dest_ident);
+
+    let disaply_impl = get_display_impl(context, &ids, &dest_ident, src);
+
+    let disaply_variant_impl = get_display_variant_impl(context, &ids, &dest_ident, src);
+
+    let disaply_from_value_impl = get_display_from_value_required(context, &variants, src);
+
+    let self_itarator_impl = get_iterator(context, &variants, &input);
+
+    let parse_impl = get_parse_impl(context, &ids, &dest_ident, src, &visibility);
+
+    let repr = context.repr(ids.len());
+
+    let id_enum = get_id_enum(
+        context,
+        &ids,
+        &dest_ident,
+        &visibility,
+        &derive_attrs,
+        repr.as_ref(),
+        &input,
+    );
+
+    let set_impl = set::get_set_impl(context, &ids, &dest_ident, &visibility)?;
+
+    let iter_impl = iter::get_iter_impl(context, &ids, &dest_ident, &visibility);
+
+    let ordinal_impl = ordinal::get_ordinal_impl(context, &ids, &dest_ident);
+
+    let map_impl = map::get_map_impl(context, &ids, &dest_ident, &visibility);
+
+    let nested_impl = nested::get_nested_impl(
+        context,
+        &variants,
+        &dest_ident,
+        &visibility,
+        &derive_attrs,
+        optional,
+        &input,
+    );
+
+    let serde_impl = serde::get_serde_impl(context, &ids, &dest_ident, src, repr.as_ref());
+
+    let traits_impl = get_traits_impl(
+        context,
+        &ids,
+        &dest_ident,
+        &getter_ident,
+        &getter_output,
+        &visibility,
+        &input,
+    );
+
+    let repr_impl = get_repr_impl(repr.as_ref(), &ids, &dest_ident, src)?;
+
+    let predicates_impl = predicate::get_predicates_impl(context, &variants, &dest_ident, &input)?;
+
+    let accessors_impl = accessor::get_accessors_impl(context, &variants, &input)?;
+
+    let values_impl = value::get_values_impl(context, &variants, &dest_ident, &input)?;
+
+    let generated = quote! {
+        #getter_impl
+
+        #id_enum
+
+        #self_itarator_impl
+
+        #meta_impl
+
+        #iter_impl
+
+        #ordinal_impl
+
+        #disaply_impl
+
+        #disaply_variant_impl
+
+        #disaply_from_value_impl
+
+        #parse_impl
+
+        #repr_impl
+
+        #traits_impl
+
+        #set_impl
+
+        #map_impl
+
+        #serde_impl
+
+        #nested_impl
+
+        #values_impl
+
+        #predicates_impl
+
+        #accessors_impl
+    };
+
+    let generated = if variants.iter().any(Variant::deprecated) {
+        allow_deprecated(generated)
+    } else {
+        generated
+    };
+
+    Ok(quote! {
+        #input
+
+        #generated
+    })
+}
+
+/// Adds `#[allow(deprecated)]` to every generated item, because generated code refers to
+/// deprecated variants of both enums.
+fn allow_deprecated(items: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    match syn::parse2::<syn::File>(items.clone()) {
+        Ok(file) => file
+            .items
+            .iter()
+            .map(|item| quote! { #[allow(deprecated)] #item })
+            .collect(),
+        Err(_) => items,
+    }
+}
+
+/// Generates the definition of the ID enum with its docs and attributes.
+fn get_id_enum(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &proc_macro2::Ident,
+    visibility: &syn::Visibility,
+    derive_attrs: &[Attribute],
+    repr: Option<&proc_macro2::Ident>,
+    input: &ItemEnum,
+) -> proc_macro2::TokenStream {
+    let src = &input.ident;
+    let id_variants = variants.iter().map(|v| {
+        let cfg = &v.cfg_attrs;
+        let docs = v.id_docs(src);
+        let deprecated = &v.deprecated_attrs;
+        let id_ident = v.id_ident();
+        quote! {
+            #(#cfg)*
+            #(#docs)*
+            #(#deprecated)*
+            #id_ident
+        }
+    });
+    let doc = cx.doc(src);
+    let forwarded_attrs: Vec<Attribute> = cx.forwarded_attrs(&input.attrs);
+    let repr_attr = repr.map(|ty| quote! { #[repr(#ty)] });
+    quote! {
+        #[doc = #doc]
+        #(#derive_attrs)*
+        #(#forwarded_attrs)*
+        #repr_attr
+        #visibility enum #dest_ident {
+            #(#id_variants),*
+        }
+    }
+}
+
+/// Generates the getter of the ID for the source enum.
+fn get_getter_impl(
+    variants: &[Variant],
+    dest_ident: &proc_macro2::Ident,
+    getter_ident: &proc_macro2::Ident,
+    getter_output: &proc_macro2::TokenStream,
+    optional: bool,
+    input: &ItemEnum,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let src = &input.ident;
+    let match_arms = variants
+        .iter()
+        .map(|v| get_arm(v, src, dest_ident, optional));
+    quote! {
+        impl #impl_generics #src #ty_generics #where_clause {
+            /// Returns the corresponding ID variant for the enum instance.
+            ///
+            pub fn #getter_ident(&self) -> #getter_output {
+                match self {
+                    #(#match_arms)*
+                }
+            }
+        }
+    }
+}
+
+fn get_arm(
+    variant: &Variant,
+    src: &proc_macro2::Ident,
+    dest_ident: &proc_macro2::Ident,
+    optional: bool,
+) -> proc_macro2::TokenStream {
+    let variant_ident = &variant.ident;
+    let id_ident = variant.id_ident();
+    let value = if variant.skip() {
+        if optional {
+            quote! { None }
+        } else {
+            quote! { panic!("Variant {}::{} doesn't have an ID", stringify!(#src), stringify!(#variant_ident)) }
+        }
+    } else if optional {
+        quote! { Some(#dest_ident::#id_ident) }
+    } else {
+        quote! { #dest_ident::#id_ident }
+    };
+    let cfg = &variant.cfg_attrs;
+    match &variant.fields {
+        Fields::Unit => {
+            quote! {
+                #(#cfg)*
+                #src::#variant_ident => #value,
+            }
+        }
+        Fields::Unnamed(_) => {
+            quote! {
+                #(#cfg)*
+                #src::#variant_ident(..) => #value,
+            }
+        }
+        Fields::Named(_) => {
+            quote! {
+                #(#cfg)*
+                #src::#variant_ident{..} => #value,
+            }
+        }
+    }
+}
+
+fn get_meta_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    let count = get_count(variants);
+    let ids = variants.iter().map(|v| {
+        let cfg = &v.cfg_attrs;
+        let id_ident = v.id_ident();
+        quote! { #(#cfg)* #dest_ident::#id_ident }
+    });
+    let names = variants.iter().map(|v| {
+        let cfg = &v.cfg_attrs;
+        let name = get_name(cx, v);
+        quote! { #(#cfg)* #name }
+    });
+    let deprecation_arms = variants.iter().map(|v| {
+        let cfg = &v.cfg_attrs;
+        let id_ident = v.id_ident();
+        let deprecated = v.deprecated();
+        let note = v
+            .deprecation_note()
+            .map_or_else(|| quote! { None }, |note| quote! { Some(#note) });
+        quote! { #(#cfg)* #dest_ident::#id_ident => (#deprecated, #note), }
+    });
+    quote! {
+        impl #dest_ident {
+            /// Number of variants.
+            pub const COUNT: usize = #count;
+
+            /// All variants in the order of declaration.
+            pub const ALL: [#dest_ident; #dest_ident::COUNT] = [#(#ids),*];
+
+            /// Names of all variants in the order of declaration.
+            pub const NAMES: [&'static str; #dest_ident::COUNT] = [#(#names),*];
+
+            /// Returns all variants in the order of declaration.
+            pub fn as_vec() -> Vec<#dest_ident> {
+                Vec::from(#dest_ident::ALL)
+            }
+
+            const fn deprecation(&self) -> (bool, Option<&'static str>) {
+                match self {
+                    #(#deprecation_arms)*
+                }
+            }
+
+            /// Returns `true` if the variant is marked with `#[deprecated]`.
+            pub const fn is_deprecated(&self) -> bool {
+                self.deprecation().0
+            }
+
+            /// Returns the note of `#[deprecated]` of the variant, if the note is defined.
+            pub const fn deprecation_note(&self) -> Option<&'static str> {
+                self.deprecation().1
+            }
+        }
+    }
+}
+
+fn get_display_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &proc_macro2::Ident,
+    src: &proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    if cx.display_required() {
+        let arms = variants.iter().map(|v| {
+            let cfg = &v.cfg_attrs;
+            let variant = v.id_ident();
+            let label = get_label(cx, v, src);
+            quote! {
+                #(#cfg)*
+                #dest_ident::#variant => #label,
+            }
+        });
+        quote! {
+            impl std::fmt::Display for #dest_ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "{}",
+                        match self {
+                            #(#arms)*
+                        }
+                    )
+                }
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+fn get_display_variant_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &proc_macro2::Ident,
+    src: &proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    if cx.display_variant() || cx.display_variant_snake() {
+        let arms = variants.iter().map(|v| {
+            let cfg = &v.cfg_attrs;
+            let variant = v.id_ident();
+            let label = get_label(cx, v, src);
+            quote! {
+                #(#cfg)*
+                #dest_ident::#variant => #label,
+            }
+        });
+        quote! {
+            impl std::fmt::Display for #dest_ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "{}",
+                        match self {
+                            #(#arms)*
+                        }
+                    )
+                }
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Returns the name of the variant without reference to the name of the enum.
+///
+/// If the variant has `rename`, it's used as it is. Otherwise the identifier of the variant is
+/// converted with `rename_all`.
+fn get_name(cx: &Context, variant: &Variant) -> String {
+    variant.rename().map_or_else(
+        || {
+            cx.rename_all().map_or_else(
+                || variant.ident.to_string(),
+                |case| case.convert(variant.ident.to_string()),
+            )
+        },
+        String::from,
+    )
+}
+
+/// Returns the position of every variant in the enum as an expression of the integer type `ty`.
+///
+/// A variant behind `#[cfg(...)]` shifts the positions of the following variants only if it's
+/// present, so such positions are computed with `cfg!()`.
+fn get_indexes(variants: &[Variant], ty: &proc_macro2::Ident) -> Vec<proc_macro2::TokenStream> {
+    (0..variants.len())
+        .map(|i| get_position(&variants[..i], ty))
+        .collect()
+}
+
+/// Returns the number of variants as a `usize` expression, taking into account `#[cfg(...)]`.
+fn get_count(variants: &[Variant]) -> proc_macro2::TokenStream {
+    get_position(variants, &format_ident!("usize"))
+}
+
+/// Returns the number of present variants of `preceding` as an expression of the integer type `ty`.
+fn get_position(preceding: &[Variant], ty: &proc_macro2::Ident) -> proc_macro2::TokenStream {
+    let base = proc_macro2::Literal::usize_unsuffixed(
+        preceding.iter().filter(|v| v.cfg_attrs.is_empty()).count(),
+    );
+    let conditions: Vec<proc_macro2::TokenStream> = preceding
+        .iter()
+        .filter_map(Variant::cfg_predicate)
+        .collect();
+    if conditions.is_empty() {
+        quote! { #base }
+    } else {
+        quote! { (#base #(+ (cfg!(#conditions) as #ty))*) }
+    }
+}
+
+/// Checks that at least one variant is left for the generated enum
+fn check_not_empty(input: &ItemEnum, variants: &[Variant]) -> Result<(), syn::Error> {
+    if variants.iter().all(Variant::skip) {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            format!(
+                "Enum \"{}\" has no variants to generate identifiers for",
+                input.ident
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the names of the methods of the source enum, which don't depend on single variants:
+/// the getter, `as_vec()` of `iterator` and `deep_id()` of nested variants.
+fn get_source_methods(cx: &Context, variants: &[Variant], src: &proc_macro2::Ident) -> Vec<String> {
+    let mut methods = vec![cx.getter_name(src).to_string()];
+    if cx.iterator() {
+        methods.push(String::from("as_vec"));
+    }
+    if variants.iter().any(|v| !v.skip() && v.nested()) {
+        methods.push(String::from("deep_id"));
+    }
+    methods
+}
+
+/// Checks that all variants of the generated ID enum have unique names.
+fn check_names(cx: &Context, variants: &[Variant]) -> Result<(), syn::Error> {
+    let normalize = |name: String| {
+        if cx.parse_ignore_case() {
+            name.to_ascii_lowercase()
+        } else {
+            name
+        }
+    };
+    let mut names: Vec<(String, &Variant)> = Vec::new();
+    for variant in variants {
+        let name = normalize(get_name(cx, variant));
+        if let Some((_, first)) = names.iter().find(|(n, _)| *n == name) {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!(
+                    "Variants \"{}\" and \"{}\" have the same name \"{}\"",
+                    first.ident,
+                    variant.ident,
+                    get_name(cx, variant)
+                ),
+            ));
+        }
+        names.push((name, variant));
+    }
+    Ok(())
+}
+
+/// Returns the value of an attribute given either as a string literal (`key = "value"`) or as
+/// an identifier (`key = value`).
+fn get_str_value(expr: &syn::Expr) -> Option<syn::LitStr> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(value),
+            ..
+        }) => Some(value.clone()),
+        syn::Expr::Path(p) if p.qself.is_none() => p
+            .path
+            .get_ident()
+            .map(|ident| syn::LitStr::new(&ident.to_string(), ident.span())),
+        _ => None,
+    }
+}
+
+/// Checks that attributes are defined only once and don't contradict each other.
+///
+/// # Arguments
+///
+/// * `names` - The names of the attributes in the order of definition.
+/// * `spans` - The spans of the keys of the attributes in the same order.
+/// * `lists` - The names of the attributes, which can be defined more than once.
+/// * `conflicts` - The pairs of attributes, which cannot be used together.
+///
+/// # Returns
+///
+/// * The errors pointing at the later attribute of every duplicate and contradictory pair.
+fn check_combinations(
+    names: &[String],
+    spans: &[proc_macro2::Span],
+    lists: &[&str],
+    conflicts: &[(&str, &str)],
+) -> Vec<syn::Error> {
+    let mut errors: Vec<syn::Error> = Vec::new();
+    for (i, name) in names.iter().enumerate() {
+        if !lists.contains(&name.as_str()) && names[..i].contains(name) {
+            errors.push(syn::Error::new(
+                spans[i],
+                format!("Attribute \"{name}\" is defined more than once"),
+            ));
+        }
+    }
+    for (first, second) in conflicts {
+        let first = names.iter().position(|name| name == first);
+        let second = names.iter().position(|name| name == second);
+        if let (Some(first), Some(second)) = (first, second) {
+            let (earlier, later) = (first.min(second), first.max(second));
+            errors.push(syn::Error::new(
+                spans[later],
+                format!(
+                    "Attribute \"{}\" cannot be used together with \"{}\"",
+                    names[later], names[earlier]
+                ),
+            ));
+        }
+    }
+    errors
+}
+
+/// Combines errors into a single one, so that all of them are reported in one pass.
+fn combine_errors(errors: Vec<syn::Error>) -> Result<(), syn::Error> {
+    errors
+        .into_iter()
+        .reduce(|mut combined, error| {
+            combined.combine(error);
+            combined
+        })
+        .map_or(Ok(()), Err)
+}
+
+/// Creates an identifier from a name checked with `check_ident`; keywords are turned into raw identifiers.
+fn get_ident(name: &str, span: proc_macro2::Span) -> proc_macro2::Ident {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    if syn::parse_str::<proc_macro2::Ident>(name).is_ok() {
+        proc_macro2::Ident::new(name, span)
+    } else {
+        proc_macro2::Ident::new_raw(name, span)
+    }
+}
+
+/// Checks that the value of an attribute is a valid identifier. Keywords (`type` or `r#type`)
+/// are accepted and used as raw identifiers, except those, which cannot be raw (`self`, `crate` etc.).
+fn check_ident(value: &syn::LitStr) -> Result<(), syn::Error> {
+    if is_ident(&value.value()) {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            value.span(),
+            format!("\"{}\" isn't a valid identifier", value.value()),
+        ))
+    }
+}
+
+/// Determines the name can be used as an identifier (probably a raw one) following the rules
+/// of Rust: `XID_Start` or `_` followed by `XID_Continue`.
+fn is_ident(name: &str) -> bool {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c == '_' || unicode_ident::is_xid_start(c))
+        && chars.all(unicode_ident::is_xid_continue)
+        && !["_", "self", "Self", "super", "crate"].contains(&name)
+}
+
+/// Returns the string representation of the variant, which is used by the active display mode.
+/// The same string is expected by the parser.
+fn get_label(cx: &Context, variant: &Variant, src: &proc_macro2::Ident) -> String {
+    let name = get_name(cx, variant);
+    if cx.display_required() {
+        format!("{src} :: {name}")
+    } else {
+        name
+    }
+}
+
+fn get_display_from_value_required(
+    cx: &Context,
+    variants: &[Variant],
+    src: &proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    if cx.display_from_value_required() {
+        let arms = variants.iter().map(|v| {
+            let cfg = &v.cfg_attrs;
+            let variant = &v.ident;
+            quote! {
+                #(#cfg)*
+                #src::#variant(v) => v.to_string(),
+            }
+        });
+        quote! {
+            impl std::fmt::Display for #src {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "{}",
+                        match self {
+                            #(#arms)*
+                        }
+                    )
+                }
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+fn get_iterator(cx: &Context, variants: &[Variant], input: &ItemEnum) -> proc_macro2::TokenStream {
+    if cx.iterator() {
+        let src = &input.ident;
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+        let iter_values = variants.iter().map(|v| {
+            let cfg = &v.cfg_attrs;
+            let sample = v.sample().cloned().unwrap_or_else(|| get_sample(v, src));
+            quote! {
+                #(#cfg)*
+                #sample
+            }
+        });
+        // Fields, which depend on type parameters, are created only if the parameters allow it.
+        let params: Vec<&proc_macro2::Ident> = input
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect();
+        let bounds: Vec<&syn::Type> = variants
+            .iter()
+            .filter(|v| v.sample().is_none())
+            .flat_map(|v| v.fields.iter().map(|field| &field.ty))
+            .filter(|ty| has_param(ty.to_token_stream(), &params))
+            .collect();
+        let bounds = if bounds.is_empty() {
+            quote! {}
+        } else {
+            quote! { where #(#bounds: Default),* }
+        };
+        quote! {
+            impl #impl_generics #src #ty_generics #where_clause {
+                /// Returns all variants of the enum in the order of declaration.
+                pub fn as_vec() -> Vec<Self> #bounds {
+                    Vec::from([#(#iter_values),*])
+                }
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Determines the type mentions one of the type parameters of the enum.
+fn has_param(tokens: proc_macro2::TokenStream, params: &[&proc_macro2::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => params.iter().any(|param| **param == ident),
+        proc_macro2::TokenTree::Group(group) => has_param(group.stream(), params),
+        _ => false,
+    })
+}
+
+/// Returns the expression, which creates the variant with default values of fields.
+///
+/// The default value of a field is created through a trait local to the variant, which reports
+/// the missing `Default` at the type of the field and names the variant.
+fn get_sample(variant: &Variant, src: &proc_macro2::Ident) -> proc_macro2::TokenStream {
+    let ident = &variant.ident;
+    let sample = |ty: &syn::Type| {
+        quote_spanned! { ty.span()=>
+            <#ty as __EnumIdsSample>::sample()
+        }
+    };
+    let value = match &variant.fields {
+        Fields::Unit => return quote! { #src::#ident },
+        Fields::Unnamed(fields) => {
+            let values = fields.unnamed.iter().map(|field| sample(&field.ty));
+            quote! { #src::#ident(#(#values),*) }
+        }
+        Fields::Named(fields) => {
+            let values = fields.named.iter().map(|field| {
+                let name = &field.ident;
+                let value = sample(&field.ty);
+                quote! { #name: #value }
+            });
+            quote! { #src::#ident { #(#values),* } }
+        }
+    };
+    let message = format!(
+        "`{{Self}}` doesn't implement `Default`, so `iterator` cannot create variant `{src}::{ident}`"
+    );
+    let label =
+        format!("add `#[enum_ids(sample = ...)]` to variant `{ident}` or implement `Default`");
+    quote! {
+        {
+            #[diagnostic::on_unimplemented(message = #message, label = #label)]
+            trait __EnumIdsSample: Sized {
+                fn sample() -> Self;
+            }
+
+            impl<F: Default> __EnumIdsSample for F {
+                fn sample() -> Self {
+                    F::default()
+                }
+            }
+
+            #value
+        }
+    }
+}
+
+fn get_parse_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &proc_macro2::Ident,
+    src: &proc_macro2::Ident,
+    visibility: &syn::Visibility,
+) -> proc_macro2::TokenStream {
+    if cx.parse_required() {
+        let error_ident = format_ident!("{}ParseError", dest_ident);
+        let labels: Vec<String> = variants.iter().map(|v| get_label(cx, v, src)).collect();
+        let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+        let variants = variants.iter().map(Variant::id_ident);
+        let body = if cx.parse_ignore_case() {
+            quote! {
+                #(
+                    #(#cfgs)*
+                    if value.eq_ignore_ascii_case(#labels) {
+                        return Ok(#dest_ident::#variants);
+                    }
+                )*
+                Err(#error_ident { value: value.to_owned() })
+            }
+        } else {
+            quote! {
+                match value {
+                    #(
+                        #(#cfgs)*
+                        #labels => Ok(#dest_ident::#variants),
+                    )*
+                    _ => Err(#error_ident { value: value.to_owned() }),
+                }
+            }
+        };
+        quote! {
+            /// Error, which is returned if a string cannot be parsed into the ID enum.
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #visibility struct #error_ident {
+                value: String,
+            }
+
+            impl #error_ident {
+                /// List of all strings, which can be parsed.
+                pub const EXPECTED: &'static [&'static str] = &[#(#(#cfgs)* #labels),*];
+
+                /// Returns the string, which was not recognized.
+                pub fn value(&self) -> &str {
+                    &self.value
+                }
+            }
+
+            impl std::fmt::Display for #error_ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "unknown {} \"{}\"; expected one of: ",
+                        stringify!(#dest_ident),
+                        self.value
+                    )?;
+                    for (i, label) in Self::EXPECTED.iter().enumerate() {
+                        if i > 0 {
+                            f.write_str(", ")?;
+                        }
+                        write!(f, "\"{}\"", label)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl std::error::Error for #error_ident {}
+
+            impl std::str::FromStr for #dest_ident {
+                type Err = #error_ident;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    #body
+                }
+            }
+
+            impl std::convert::TryFrom<&str> for #dest_ident {
+                type Error = #error_ident;
+
+                fn try_from(value: &str) -> Result<Self, Self::Error> {
+                    <#dest_ident as std::str::FromStr>::from_str(value)
+                }
+            }
+
+            impl std::convert::TryFrom<String> for #dest_ident {
+                type Error = #error_ident;
+
+                fn try_from(value: String) -> Result<Self, Self::Error> {
+                    <#dest_ident as std::str::FromStr>::from_str(&value)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+fn get_repr_impl(
+    repr: Option<&proc_macro2::Ident>,
+    variants: &[Variant],
+    dest_ident: &proc_macro2::Ident,
+    src: &proc_macro2::Ident,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let Some(ty) = repr else {
+        return Ok(quote! {});
+    };
+    let max: u128 = match ty.to_string().as_str() {
+        "u8" => u8::MAX.into(),
+        "u16" => u16::MAX.into(),
+        "i8" => i8::MAX.unsigned_abs().into(),
+        "i16" => i16::MAX.unsigned_abs().into(),
+        _ => u32::MAX.into(),
+    };
+    if variants.len() as u128 > max + 1 {
+        return Err(syn::Error::new(
+            src.span(),
+            format!("Type \"{ty}\" cannot hold {} variants", variants.len()),
+        ));
+    }
+    let getter = format_ident!("as_{}", ty);
+    let values = get_indexes(variants, ty);
+    let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+    let variants: Vec<proc_macro2::Ident> = variants.iter().map(Variant::id_ident).collect();
+    Ok(quote! {
+        impl #dest_ident {
+            /// Returns the integer representation of the ID.
+            pub const fn #getter(&self) -> #ty {
+                match self {
+                    #(
+                        #(#cfgs)*
+                        #dest_ident::#variants => #values,
+                    )*
+                }
+            }
+        }
+
+        impl From<#dest_ident> for #ty {
+            fn from(id: #dest_ident) -> Self {
+                id.#getter()
+            }
+        }
+
+        impl std::convert::TryFrom<#ty> for #dest_ident {
+            type Error = #ty;
+
+            /// Converts the integer into the ID. The rejected value is returned as an error.
+            fn try_from(value: #ty) -> Result<Self, Self::Error> {
+                match value {
+                    #(
+                        #(#cfgs)*
+                        value if value == #values => Ok(#dest_ident::#variants),
+                    )*
+                    _ => Err(value),
+                }
+            }
+        }
+    })
+}
+
+/// Determines the source enum can implement `HasId`: the trait exposes the ID enum as an
+/// associated type, which isn't possible if the ID enum is less visible than the source enum.
+fn has_id(visibility: &syn::Visibility, input: &ItemEnum) -> bool {
+    matches!(visibility, syn::Visibility::Public(..))
+        || quote!(#visibility).to_string() == input.vis.to_token_stream().to_string()
+}
+
+fn get_traits_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &proc_macro2::Ident,
+    getter_ident: &proc_macro2::Ident,
+    getter_output: &proc_macro2::TokenStream,
+    visibility: &syn::Visibility,
+    input: &ItemEnum,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let src = &input.ident;
+    let has_id_impl = if has_id(visibility, input) {
+        quote! {
+            impl #impl_generics ::enum_ids::HasId for #src #ty_generics #where_clause {
+                type Id = #getter_output;
+
+                fn id(&self) -> Self::Id {
+                    Self::#getter_ident(self)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+    let ids: Vec<proc_macro2::Ident> = variants.iter().map(Variant::id_ident).collect();
+    let names = variants.iter().map(|v| get_name(cx, v));
+    quote! {
+        #has_id_impl
+
+        impl ::enum_ids::IdEnum for #dest_ident {
+            const COUNT: usize = #dest_ident::COUNT;
+
+            fn all() -> &'static [Self] {
+                &#dest_ident::ALL
+            }
+
+            fn name(&self) -> &'static str {
+                match self {
+                    #(
+                        #(#cfgs)*
+                        #dest_ident::#ids => #names,
+                    )*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{context::Context, get_indexes, get_name, variant::Variant};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Visibility};
+
+/// Generates `KindIdMap<V>`, a map with a value for every variant of the generated ID enum.
+///
+/// The map is backed by an array `[V; KindId::COUNT]`; the value of a variant is stored at the
+/// position of the variant in the enum. If the feature `serde` is enabled, the map also implements
+/// `Serialize` and `Deserialize` using the names of variants as keys.
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - The variants of the generated ID enum.
+/// * `dest_ident` - The name of the generated ID enum.
+/// * `visibility` - The visibility of the generated ID enum.
+///
+/// # Returns
+///
+/// * Generated code.
+pub fn get_map_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &Ident,
+    visibility: &Visibility,
+) -> TokenStream {
+    if !cx.map() {
+        return quote! {};
+    }
+    let map_ident = format_ident!("{}Map", dest_ident);
+    let ids: Vec<Ident> = variants.iter().map(Variant::id_ident).collect();
+    let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+    let indexes = get_indexes(variants, &format_ident!("usize"));
+    let accessors = get_accessors(dest_ident, &map_ident);
+    let traits = get_traits(dest_ident, &map_ident);
+    let serde = get_serde(cx, variants, dest_ident, &map_ident);
+    quote! {
+        /// Map with a value for every ID, which is backed by an array.
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        #visibility struct #map_ident<V> {
+            values: [V; #dest_ident::COUNT],
+        }
+
+        impl<V> #map_ident<V> {
+            const fn index(id: &#dest_ident) -> usize {
+                match id {
+                    #(
+                        #(#cfgs)*
+                        #dest_ident::#ids => #indexes,
+                    )*
+                }
+            }
+
+            fn id(index: usize) -> #dest_ident {
+                match index {
+                    #(
+                        #(#cfgs)*
+                        index if index == #indexes => #dest_ident::#ids,
+                    )*
+                    _ => unreachable!("Invalid index of ID"),
+                }
+            }
+
+            /// Creates a map from values in the order of declaration of IDs.
+            pub const fn from_array(values: [V; #dest_ident::COUNT]) -> Self {
+                Self { values }
+            }
+
+            /// Creates a map, where the value of each ID is returned by `f`.
+            pub fn from_fn<F: FnMut(#dest_ident) -> V>(mut f: F) -> Self {
+                Self {
+                    values: std::array::from_fn(|index| f(Self::id(index))),
+                }
+            }
+
+            /// Returns the number of values in the map, which is always the number of IDs.
+            pub const fn len(&self) -> usize {
+                #dest_ident::COUNT
+            }
+
+            /// Returns `true` if the ID enum doesn't have variants.
+            pub const fn is_empty(&self) -> bool {
+                #dest_ident::COUNT == 0
+            }
+
+            /// Returns the values in the order of declaration of IDs.
+            pub const fn as_array(&self) -> &[V; #dest_ident::COUNT] {
+                &self.values
+            }
+
+            /// Consumes the map and returns the values in the order of declaration of IDs.
+            pub fn into_array(self) -> [V; #dest_ident::COUNT] {
+                self.values
+            }
+
+            /// Converts the map into a map with values returned by `f`.
+            pub fn map<U, F: FnMut(#dest_ident, V) -> U>(self, mut f: F) -> #map_ident<U> {
+                let mut index = 0;
+                #map_ident {
+                    values: self.values.map(|value| {
+                        let id = Self::id(index);
+                        index += 1;
+                        f(id, value)
+                    }),
+                }
+            }
+        }
+
+        #accessors
+
+        #traits
+
+        #serde
+    }
+}
+
+/// Generates methods to access values of the map.
+fn get_accessors(dest_ident: &Ident, map_ident: &Ident) -> TokenStream {
+    quote! {
+        impl<V> #map_ident<V> {
+            /// Returns the value of the ID.
+            pub fn get(&self, id: #dest_ident) -> &V {
+                &self.values[Self::index(&id)]
+            }
+
+            /// Returns the mutable value of the ID.
+            pub fn get_mut(&mut self, id: #dest_ident) -> &mut V {
+                &mut self.values[Self::index(&id)]
+            }
+
+            /// Sets the value of the ID and returns the previous value.
+            pub fn insert(&mut self, id: #dest_ident, value: V) -> V {
+                std::mem::replace(self.get_mut(id), value)
+            }
+
+            /// Returns an iterator over IDs.
+            pub fn keys(&self) -> impl DoubleEndedIterator<Item = #dest_ident> + ExactSizeIterator {
+                (0..#dest_ident::COUNT).map(Self::id)
+            }
+
+            /// Returns an iterator over values in the order of declaration of IDs.
+            pub fn values(&self) -> std::slice::Iter<'_, V> {
+                self.values.iter()
+            }
+
+            /// Returns an iterator over mutable values in the order of declaration of IDs.
+            pub fn values_mut(&mut self) -> std::slice::IterMut<'_, V> {
+                self.values.iter_mut()
+            }
+
+            /// Returns an iterator over pairs of ID and value in the order of declaration of IDs.
+            pub fn iter(&self) -> <&Self as IntoIterator>::IntoIter {
+                self.into_iter()
+            }
+
+            /// Returns an iterator over pairs of ID and mutable value in the order of declaration of IDs.
+            pub fn iter_mut(&mut self) -> <&mut Self as IntoIterator>::IntoIter {
+                self.into_iter()
+            }
+        }
+    }
+}
+
+/// Generates implementations of standard traits for the map.
+fn get_traits(dest_ident: &Ident, map_ident: &Ident) -> TokenStream {
+    quote! {
+        impl<V> std::ops::Index<#dest_ident> for #map_ident<V> {
+            type Output = V;
+
+            fn index(&self, id: #dest_ident) -> &V {
+                self.get(id)
+            }
+        }
+
+        impl<V> std::ops::IndexMut<#dest_ident> for #map_ident<V> {
+            fn index_mut(&mut self, id: #dest_ident) -> &mut V {
+                self.get_mut(id)
+            }
+        }
+
+        impl<V: Default> Default for #map_ident<V> {
+            fn default() -> Self {
+                Self::from_fn(|_| V::default())
+            }
+        }
+
+        impl<V: std::fmt::Debug> std::fmt::Debug for #map_ident<V> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_map()
+                    .entries(#dest_ident::NAMES.iter().zip(self.values.iter()))
+                    .finish()
+            }
+        }
+
+        impl<V> IntoIterator for #map_ident<V> {
+            type Item = (#dest_ident, V);
+            type IntoIter = std::iter::Map<
+                std::iter::Enumerate<std::array::IntoIter<V, { #dest_ident::COUNT }>>,
+                fn((usize, V)) -> (#dest_ident, V),
+            >;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, value)| (Self::id(index), value))
+            }
+        }
+
+        impl<'a, V> IntoIterator for &'a #map_ident<V> {
+            type Item = (#dest_ident, &'a V);
+            type IntoIter = std::iter::Map<
+                std::iter::Enumerate<std::slice::Iter<'a, V>>,
+                fn((usize, &'a V)) -> (#dest_ident, &'a V),
+            >;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| (#map_ident::<V>::id(index), value))
+            }
+        }
+
+        impl<'a, V> IntoIterator for &'a mut #map_ident<V> {
+            type Item = (#dest_ident, &'a mut V);
+            type IntoIter = std::iter::Map<
+                std::iter::Enumerate<std::slice::IterMut<'a, V>>,
+                fn((usize, &'a mut V)) -> (#dest_ident, &'a mut V),
+            >;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.values
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(index, value)| (#map_ident::<V>::id(index), value))
+            }
+        }
+    }
+}
+
+/// Generates `Serialize` and `Deserialize` for the map, if the feature `serde` is enabled.
+/// The names of variants are used as keys.
+fn get_serde(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &Ident,
+    map_ident: &Ident,
+) -> TokenStream {
+    if !cfg!(feature = "serde") {
+        return quote! {};
+    }
+    let names = variants.iter().map(|v| get_name(cx, v));
+    let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+    let indexes = get_indexes(variants, &format_ident!("usize"));
+    let expecting = format!("map with keys of {dest_ident}");
+    quote! {
+        impl<V: ::enum_ids::__private::serde::Serialize> ::enum_ids::__private::serde::Serialize
+            for #map_ident<V>
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::enum_ids::__private::serde::Serializer,
+            {
+                use ::enum_ids::__private::serde::ser::SerializeMap;
+                let mut map = serializer.serialize_map(Some(#dest_ident::COUNT))?;
+                for (name, value) in #dest_ident::NAMES.iter().zip(self.values.iter()) {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+        }
+
+        impl<'de, V: ::enum_ids::__private::serde::Deserialize<'de>>
+            ::enum_ids::__private::serde::Deserialize<'de> for #map_ident<V>
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::enum_ids::__private::serde::Deserializer<'de>,
+            {
+                use ::enum_ids::__private::serde::de::{self, MapAccess, Visitor};
+
+                struct MapVisitor<V>(std::marker::PhantomData<V>);
+
+                impl<'de, V: de::Deserialize<'de>> Visitor<'de> for MapVisitor<V> {
+                    type Value = #map_ident<V>;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str(#expecting)
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                        let mut values: [Option<V>; #dest_ident::COUNT] = std::array::from_fn(|_| None);
+                        while let Some(key) = access.next_key::<String>()? {
+                            let index = match key.as_str() {
+                                #(
+                                    #(#cfgs)*
+                                    #names => #indexes,
+                                )*
+                                _ => return Err(de::Error::unknown_field(&key, &#dest_ident::NAMES)),
+                            };
+                            if values[index].is_some() {
+                                return Err(de::Error::custom(format_args!("duplicate key `{}`", key)));
+                            }
+                            values[index] = Some(access.next_value()?);
+                        }
+                        if let Some(index) = values.iter().position(Option::is_none) {
+                            return Err(de::Error::missing_field(#dest_ident::NAMES[index]));
+                        }
+                        Ok(#map_ident {
+                            values: values.map(|value| value.expect("All values are present")),
+                        })
+                    }
+                }
+
+                deserializer.deserialize_map(MapVisitor(std::marker::PhantomData))
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use crate::{context::Context, get_name, has_id, variant::Variant};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{spanned::Spanned, Attribute, ItemEnum, Type, Visibility};
+
+/// Generates `KindPath`, the ID of a variant together with the ID of the wrapped enum for
+/// variants marked with `#[enum_ids(nested)]`, and the getter `deep_id()` of the source enum.
+///
+/// A nested variant `Kind::Net(NetKind)` gives `KindPath::Net(NetKindId)`; the ID of the wrapped
+/// enum is taken from `enum_ids::__private::Nested`, and the wrapped enum must have an ID for every
+/// variant (`enum_ids::__private::Complete`). Other variants give unit variants. The path implements
+/// `Display` and `FromStr` with the names joined by a dot (`net.connect`).
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - All variants of the source enum.
+/// * `dest_ident` - The name of the generated ID enum.
+/// * `visibility` - The visibility of the generated ID enum.
+/// * `derive_attrs` - The derive attributes of the generated ID enum.
+/// * `optional` - `true` if the getter returns `Option`.
+/// * `input` - The source enum.
+///
+/// # Returns
+///
+/// * Generated code. Only `Nested` and `Complete` are implemented if the source enum doesn't have
+///   nested variants.
+pub fn get_nested_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &Ident,
+    visibility: &Visibility,
+    derive_attrs: &[Attribute],
+    optional: bool,
+    input: &ItemEnum,
+) -> TokenStream {
+    let nested_impl = get_nested_trait_impl(cx, dest_ident, visibility, optional, input);
+    if !variants.iter().any(|v| !v.skip() && v.nested()) {
+        return nested_impl;
+    }
+    let src = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let path_ident = format_ident!("{}Path", src);
+    let ids: Vec<&Variant> = variants.iter().filter(|v| !v.skip()).collect();
+    let path_variants = ids.iter().map(|v| {
+        let id_ident = v.id_ident();
+        let cfg = &v.cfg_attrs;
+        let docs = v.id_docs(src);
+        let deprecated = &v.deprecated_attrs;
+        if let Some(ty) = v.nested_ty().filter(|_| v.nested()) {
+            let id = get_nested_id(ty);
+            quote! { #(#cfg)* #(#docs)* #(#deprecated)* #id_ident(#id) }
+        } else {
+            quote! { #(#cfg)* #(#docs)* #(#deprecated)* #id_ident }
+        }
+    });
+    let getter_output = if optional {
+        quote! { Option<#path_ident> }
+    } else {
+        quote! { #path_ident }
+    };
+    let arms = variants
+        .iter()
+        .map(|v| get_arm(v, src, &path_ident, optional));
+    let checks = ids
+        .iter()
+        .filter_map(|v| v.nested_ty().filter(|_| v.nested()))
+        .map(get_complete_check);
+    let methods = get_methods(&ids, dest_ident, &path_ident);
+    let traits = get_traits(cx, &ids, &path_ident, visibility);
+    quote! {
+        #nested_impl
+
+        impl #impl_generics #src #ty_generics #where_clause {
+            /// Returns the ID of the variant together with the ID of the wrapped enum for nested variants.
+            pub fn deep_id(&self) -> #getter_output {
+                #(#checks)*
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        /// ID of a variant together with the ID of the wrapped enum for nested variants.
+        #(#derive_attrs)*
+        #visibility enum #path_ident {
+            #(#path_variants),*
+        }
+
+        #methods
+
+        #traits
+    }
+}
+
+/// Implements `Nested` for the source enum, which can be wrapped by nested variants of other
+/// enums, if it implements `HasId`. `Complete` is implemented if it has an ID for every variant;
+/// otherwise the enum can't be wrapped and `nested_id()` is never called.
+fn get_nested_trait_impl(
+    cx: &Context,
+    dest_ident: &Ident,
+    visibility: &Visibility,
+    optional: bool,
+    input: &ItemEnum,
+) -> TokenStream {
+    if !has_id(visibility, input) {
+        return quote! {};
+    }
+    let src = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let getter_ident = cx.getter_name(src);
+    let (nested_id, complete_impl) = if optional {
+        (
+            quote! {
+                match Self::#getter_ident(self) {
+                    Some(id) => id,
+                    None => panic!("Variant of {} doesn't have an ID", stringify!(#src)),
+                }
+            },
+            quote! {},
+        )
+    } else {
+        (
+            quote! { Self::#getter_ident(self) },
+            quote! {
+                impl #impl_generics ::enum_ids::__private::Complete for #src #ty_generics #where_clause {}
+            },
+        )
+    };
+    quote! {
+        #complete_impl
+
+        impl #impl_generics ::enum_ids::__private::Nested for #src #ty_generics #where_clause {
+            type Id = #dest_ident;
+
+            fn nested_id(&self) -> Self::Id {
+                #nested_id
+            }
+
+            fn nested_ids() -> Vec<Self::Id> {
+                Vec::from(#dest_ident::ALL)
+            }
+        }
+    }
+}
+
+/// Returns the ID of the wrapped enum of a nested variant. The span of the type is kept, so
+/// the compiler reports a wrapped enum without `Nested` at the field of the variant.
+fn get_nested_id(ty: &Type) -> TokenStream {
+    quote_spanned! {ty.span()=> <#ty as ::enum_ids::__private::Nested>::Id }
+}
+
+/// Returns the check, which reports a wrapped enum skipping variants at the field of the variant.
+fn get_complete_check(ty: &Type) -> TokenStream {
+    quote_spanned! {ty.span()=> ::enum_ids::__private::assert_complete::<#ty>(); }
+}
+
+/// Generates the arm of `deep_id()` for the variant.
+fn get_arm(variant: &Variant, src: &Ident, path_ident: &Ident, optional: bool) -> TokenStream {
+    let variant_ident = &variant.ident;
+    let id_ident = variant.id_ident();
+    let (pattern, value) = if let Some(ty) = variant.nested_ty().filter(|_| variant.nested()) {
+        (
+            quote! { #src::#variant_ident(inner) },
+            quote! { #path_ident::#id_ident(<#ty as ::enum_ids::__private::Nested>::nested_id(inner)) },
+        )
+    } else {
+        (variant.pattern(src), quote! { #path_ident::#id_ident })
+    };
+    let value = if variant.skip() {
+        if optional {
+            quote! { None }
+        } else {
+            quote! { panic!("Variant {}::{} doesn't have an ID", stringify!(#src), stringify!(#variant_ident)) }
+        }
+    } else if optional {
+        quote! { Some(#value) }
+    } else {
+        value
+    };
+    let cfg = &variant.cfg_attrs;
+    quote! {
+        #(#cfg)*
+        #pattern => #value,
+    }
+}
+
+/// Generates methods of the path: the top level ID and the list of all paths.
+fn get_methods(ids: &[&Variant], dest_ident: &Ident, path_ident: &Ident) -> TokenStream {
+    let id_arms = ids.iter().map(|v| {
+        let cfg = &v.cfg_attrs;
+        let id_ident = v.id_ident();
+        if v.nested() {
+            quote! { #(#cfg)* #path_ident::#id_ident(..) => #dest_ident::#id_ident, }
+        } else {
+            quote! { #(#cfg)* #path_ident::#id_ident => #dest_ident::#id_ident, }
+        }
+    });
+    let paths = ids.iter().map(|v| {
+        let cfg = &v.cfg_attrs;
+        let id_ident = v.id_ident();
+        if let Some(ty) = v.nested_ty().filter(|_| v.nested()) {
+            quote! {
+                #(#cfg)*
+                paths.extend(
+                    <#ty as ::enum_ids::__private::Nested>::nested_ids()
+                        .into_iter()
+                        .map(#path_ident::#id_ident),
+                );
+            }
+        } else {
+            quote! {
+                #(#cfg)*
+                paths.push(#path_ident::#id_ident);
+            }
+        }
+    });
+    quote! {
+        impl #path_ident {
+            /// Returns the ID of the variant without the ID of the wrapped enum.
+            pub fn id(&self) -> #dest_ident {
+                match self {
+                    #(#id_arms)*
+                }
+            }
+
+            /// Returns all paths in the order of declaration, including every ID of wrapped enums.
+            pub fn as_vec() -> Vec<#path_ident> {
+                let mut paths = Vec::new();
+                #(#paths)*
+                paths
+            }
+        }
+    }
+}
+
+/// Generates `Display` and `FromStr` of the path.
+fn get_traits(
+    cx: &Context,
+    ids: &[&Variant],
+    path_ident: &Ident,
+    visibility: &Visibility,
+) -> TokenStream {
+    let error_ident = format_ident!("{}ParseError", path_ident);
+    let display_arms = ids.iter().map(|v| {
+        let cfg = &v.cfg_attrs;
+        let id_ident = v.id_ident();
+        let name = get_name(cx, v);
+        if v.nested() {
+            quote! {
+                #(#cfg)*
+                #path_ident::#id_ident(inner) => {
+                    write!(f, "{}.{}", #name, ::enum_ids::IdEnum::name(inner))
+                }
+            }
+        } else {
+            quote! { #(#cfg)* #path_ident::#id_ident => f.write_str(#name), }
+        }
+    });
+    let eq = if cx.parse_ignore_case() {
+        quote! { str::eq_ignore_ascii_case }
+    } else {
+        quote! { str::eq }
+    };
+    let parse_arms = ids.iter().map(|v| {
+        let cfg = &v.cfg_attrs;
+        let id_ident = v.id_ident();
+        let name = get_name(cx, v);
+        if let Some(ty) = v.nested_ty().filter(|_| v.nested()) {
+            quote! {
+                #(#cfg)*
+                (head, Some(tail)) if #eq(head, #name) => <#ty as ::enum_ids::__private::Nested>::nested_ids()
+                    .into_iter()
+                    .find(|inner| #eq(::enum_ids::IdEnum::name(inner), tail))
+                    .map(#path_ident::#id_ident),
+            }
+        } else {
+            quote! {
+                #(#cfg)*
+                (head, None) if #eq(head, #name) => Some(#path_ident::#id_ident),
+            }
+        }
+    });
+    quote! {
+        impl std::fmt::Display for #path_ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #(#display_arms)*
+                }
+            }
+        }
+
+        /// Error, which is returned if a string cannot be parsed into the path.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #visibility struct #error_ident {
+            value: String,
+        }
+
+        impl #error_ident {
+            /// Returns the string, which was not recognized.
+            pub fn value(&self) -> &str {
+                &self.value
+            }
+        }
+
+        impl std::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "unknown {} \"{}\"", stringify!(#path_ident), self.value)
+            }
+        }
+
+        impl std::error::Error for #error_ident {}
+
+        impl std::str::FromStr for #path_ident {
+            type Err = #error_ident;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                let (head, tail) = match value.split_once('.') {
+                    Some((head, tail)) => (head, Some(tail)),
+                    None => (value, None),
+                };
+                match (head, tail) {
+                    #(#parse_arms)*
+                    _ => None,
+                }
+                .ok_or_else(|| #error_ident { value: value.to_owned() })
+            }
+        }
+    }
+}
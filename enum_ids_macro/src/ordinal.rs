@@ -0,0 +1,120 @@
+use crate::{context::Context, get_indexes, iter::get_iter_ident, variant::Variant};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::Attribute;
+
+/// Generates navigation of the generated ID enum in the order of declaration: `index()`,
+/// `from_index()`, `next()`, `prev()`, `next_cyclic()`, `prev_cyclic()` and `range()`.
+///
+/// The index of a variant is its position in the enum, taking into account `#[cfg(...)]`.
+/// The methods take the ID by value as IDs are expected to be `Copy`.
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - The variants of the generated ID enum.
+/// * `dest_ident` - The name of the generated ID enum.
+///
+/// # Returns
+///
+/// * Generated code.
+pub fn get_ordinal_impl(cx: &Context, variants: &[Variant], dest_ident: &Ident) -> TokenStream {
+    if !cx.ordinal() {
+        return quote! {};
+    }
+    let iter_struct = get_iter_ident(dest_ident);
+    let ids: Vec<Ident> = variants.iter().map(Variant::id_ident).collect();
+    let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+    let indexes = get_indexes(variants, &format_ident!("usize"));
+    quote! {
+        impl #dest_ident {
+            /// Returns the position of the variant in the order of declaration.
+            pub const fn index(self) -> usize {
+                match self {
+                    #(
+                        #(#cfgs)*
+                        #dest_ident::#ids => #indexes,
+                    )*
+                }
+            }
+
+            /// Returns the variant at the position in the order of declaration.
+            pub const fn from_index(index: usize) -> Option<Self> {
+                match index {
+                    #(
+                        #(#cfgs)*
+                        index if index == #indexes => Some(#dest_ident::#ids),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Returns the next variant in the order of declaration or `None` for the last variant.
+            #[must_use]
+            pub const fn next(self) -> Option<Self> {
+                Self::from_index(self.index() + 1)
+            }
+
+            /// Returns the previous variant in the order of declaration or `None` for the first variant.
+            #[must_use]
+            pub const fn prev(self) -> Option<Self> {
+                match self.index().checked_sub(1) {
+                    Some(index) => Self::from_index(index),
+                    None => None,
+                }
+            }
+
+            /// Returns the next variant in the order of declaration; the last variant is followed by the first one.
+            #[must_use]
+            pub const fn next_cyclic(self) -> Self {
+                match self.next() {
+                    Some(id) => id,
+                    None => match Self::from_index(0) {
+                        Some(id) => id,
+                        None => unreachable!(),
+                    },
+                }
+            }
+
+            /// Returns the previous variant in the order of declaration; the first variant is preceded by the last one.
+            #[must_use]
+            pub const fn prev_cyclic(self) -> Self {
+                match self.prev() {
+                    Some(id) => id,
+                    None => match Self::from_index(Self::COUNT.saturating_sub(1)) {
+                        Some(id) => id,
+                        None => unreachable!(),
+                    },
+                }
+            }
+
+            /// Returns an iterator over the variants within the range in the order of declaration,
+            /// e.g. `KindId::range(KindId::B..=KindId::D)`.
+            pub fn range<R: std::ops::RangeBounds<Self>>(range: R) -> #iter_struct {
+                use std::ops::Bound;
+
+                // The bounds are borrowed, so the index is taken without requiring `Copy`.
+                let index = |id: &Self| match id {
+                    #(
+                        #(#cfgs)*
+                        #dest_ident::#ids => #indexes,
+                    )*
+                };
+                let front = match range.start_bound() {
+                    Bound::Included(id) => index(id),
+                    Bound::Excluded(id) => index(id) + 1,
+                    Bound::Unbounded => 0,
+                };
+                let back = match range.end_bound() {
+                    Bound::Included(id) => index(id) + 1,
+                    Bound::Excluded(id) => index(id),
+                    Bound::Unbounded => Self::COUNT,
+                };
+                #iter_struct {
+                    front,
+                    back: back.max(front),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    case::Case, context::Context, get_arm, get_ident, get_source_methods, variant::Variant,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::ItemEnum;
+
+/// Methods of the source enum, which are generated together with predicates.
+const RESERVED: &[&str] = &["is_one_of", "is_in_set"];
+
+/// Returns the names of all methods of the source enum generated by `predicates`.
+pub fn get_names(cx: &Context, variants: &[Variant]) -> Vec<String> {
+    if !cx.predicates() {
+        return Vec::new();
+    }
+    let reserved = RESERVED.iter().map(|name| String::from(*name));
+    variants.iter().map(get_name).chain(reserved).collect()
+}
+
+/// Returns the name of the predicate of the variant.
+fn get_name(variant: &Variant) -> String {
+    format!("is_{}", Case::Snake.convert(variant.ident.to_string()))
+}
+
+/// Generates predicates `is_a()`, `is_b()` etc. and `is_one_of(&[KindId])` for the source enum.
+/// If `set` is used as well, `is_in_set(&KindIdSet)` is generated.
+///
+/// The name of a predicate is the name of the variant converted into snake case with the
+/// prefix `is_`; names, which are keywords, are used as raw identifiers.
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - All variants of the source enum.
+/// * `dest_ident` - The name of the generated ID enum.
+/// * `input` - The source enum.
+///
+/// # Returns
+///
+/// * Generated code or an error if two variants give the same name of predicate or a predicate
+///   conflicts with another generated method of the source enum.
+pub fn get_predicates_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &Ident,
+    input: &ItemEnum,
+) -> Result<TokenStream, syn::Error> {
+    if !cx.predicates() {
+        return Ok(quote! {});
+    }
+    let src = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut reserved: Vec<String> = RESERVED.iter().map(|name| String::from(*name)).collect();
+    reserved.extend(get_source_methods(cx, variants, src));
+    let mut names: Vec<(String, &Variant)> = Vec::new();
+    let mut predicates = Vec::new();
+    for variant in variants {
+        let name = get_name(variant);
+        if reserved.contains(&name) {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!("Predicate \"{name}\" of the variant conflicts with the generated method"),
+            ));
+        }
+        if let Some((_, first)) = names.iter().find(|(n, _)| *n == name) {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!(
+                    "Variants \"{}\" and \"{}\" have the same predicate \"{name}\"",
+                    first.ident, variant.ident
+                ),
+            ));
+        }
+        let ident = get_ident(&name, variant.ident.span());
+        let doc = format!(
+            " Returns `true` if the value is `{src}::{}`.",
+            variant.ident
+        );
+        let cfg = &variant.cfg_attrs;
+        let pattern = variant.pattern(src);
+        predicates.push(quote! {
+            #(#cfg)*
+            #[doc = #doc]
+            pub const fn #ident(&self) -> bool {
+                matches!(self, #pattern)
+            }
+        });
+        names.push((name, variant));
+    }
+    let arms: Vec<TokenStream> = variants
+        .iter()
+        .map(|v| get_arm(v, src, dest_ident, true))
+        .collect();
+    let in_set = if cx.set() {
+        let set_ident = format_ident!("{}Set", dest_ident);
+        quote! {
+            /// Returns `true` if the ID of the value is present in the set.
+            pub fn is_in_set(&self, set: &#set_ident) -> bool {
+                let id: Option<#dest_ident> = match self {
+                    #(#arms)*
+                };
+                id.is_some_and(|id| set.contains(id))
+            }
+        }
+    } else {
+        quote! {}
+    };
+    Ok(quote! {
+        impl #impl_generics #src #ty_generics #where_clause {
+            #(#predicates)*
+
+            /// Returns `true` if the ID of the value is one of the given IDs.
+            pub fn is_one_of(&self, ids: &[#dest_ident]) -> bool {
+                let id: Option<#dest_ident> = match self {
+                    #(#arms)*
+                };
+                id.is_some_and(|id| {
+                    ids.iter()
+                        .any(|other| std::mem::discriminant(other) == std::mem::discriminant(&id))
+                })
+            }
+
+            #in_set
+        }
+    })
+}
@@ -0,0 +1,208 @@
+use crate::{context::Context, get_indexes, get_label, variant::Variant};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::Attribute;
+
+/// Generates `Serialize` and `Deserialize` for the generated ID enum.
+///
+/// With `serde = "name"` a variant is written as the string, which is produced by the active
+/// display mode (taking into account `rename` and `rename_all`). With `serde = "index"` a variant
+/// is written as its position in the enum using the type of `repr` (`u64` without `repr`).
+/// With `serde_any` the deserializer accepts both forms.
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - The variants of the generated ID enum.
+/// * `dest_ident` - The name of the generated ID enum.
+/// * `src` - The name of the source enum.
+/// * `repr` - The integer representation of the generated ID enum.
+///
+/// # Returns
+///
+/// * Generated code.
+pub fn get_serde_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &Ident,
+    src: &Ident,
+    repr: Option<&Ident>,
+) -> TokenStream {
+    let Some(format) = cx.serde() else {
+        return quote! {};
+    };
+    let by_index = format == "index";
+    let ty = repr.map_or_else(|| Ident::new("u64", Span::call_site()), Clone::clone);
+    let ids: Vec<Ident> = variants.iter().map(Variant::id_ident).collect();
+    let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+    let labels: Vec<String> = variants.iter().map(|v| get_label(cx, v, src)).collect();
+    let indexes = get_indexes(variants, &ty);
+    let serialize = if by_index {
+        quote! {
+            let index: #ty = match self {
+                #(
+                    #(#cfgs)*
+                    #dest_ident::#ids => #indexes,
+                )*
+            };
+            ::enum_ids::__private::serde::Serialize::serialize(&index, serializer)
+        }
+    } else {
+        quote! {
+            serializer.serialize_str(match self {
+                #(
+                    #(#cfgs)*
+                    #dest_ident::#ids => #labels,
+                )*
+            })
+        }
+    };
+    let visit_str = if by_index && !cx.serde_any() {
+        quote! {}
+    } else {
+        get_visit_str(cx, &ids, &cfgs, &labels, dest_ident)
+    };
+    let visit_index = if by_index || cx.serde_any() {
+        get_visit_index(
+            &ids,
+            &cfgs,
+            &get_indexes(variants, &format_ident!("u64")),
+            dest_ident,
+        )
+    } else {
+        quote! {}
+    };
+    let (expecting, deserialize) = if cx.serde_any() {
+        (
+            format!("name or index of {dest_ident}"),
+            format_ident!("deserialize_any"),
+        )
+    } else if by_index {
+        (
+            format!("index of {dest_ident}"),
+            match ty.to_string().as_str() {
+                "usize" => format_ident!("deserialize_u64"),
+                "isize" => format_ident!("deserialize_i64"),
+                ty => format_ident!("deserialize_{}", ty),
+            },
+        )
+    } else {
+        (
+            format!("name of {dest_ident}"),
+            format_ident!("deserialize_str"),
+        )
+    };
+    quote! {
+        impl ::enum_ids::__private::serde::Serialize for #dest_ident {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::enum_ids::__private::serde::Serializer,
+            {
+                #serialize
+            }
+        }
+
+        impl<'de> ::enum_ids::__private::serde::Deserialize<'de> for #dest_ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::enum_ids::__private::serde::Deserializer<'de>,
+            {
+                use ::enum_ids::__private::serde::de::{self, Visitor};
+
+                struct IdVisitor;
+
+                impl<'de> Visitor<'de> for IdVisitor {
+                    type Value = #dest_ident;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str(#expecting)
+                    }
+
+                    #visit_str
+
+                    #visit_index
+                }
+
+                deserializer.#deserialize(IdVisitor)
+            }
+        }
+    }
+}
+
+/// Generates `visit_str`, which recognizes variants by the strings of the active display mode.
+fn get_visit_str(
+    cx: &Context,
+    ids: &[Ident],
+    cfgs: &[&Vec<Attribute>],
+    labels: &[String],
+    dest_ident: &Ident,
+) -> TokenStream {
+    let body = if cx.parse_ignore_case() {
+        quote! {
+            #(
+                #(#cfgs)*
+                if value.eq_ignore_ascii_case(#labels) {
+                    return Ok(#dest_ident::#ids);
+                }
+            )*
+            Err(de::Error::unknown_variant(value, EXPECTED))
+        }
+    } else {
+        quote! {
+            match value {
+                #(
+                    #(#cfgs)*
+                    #labels => Ok(#dest_ident::#ids),
+                )*
+                _ => Err(de::Error::unknown_variant(value, EXPECTED)),
+            }
+        }
+    };
+    quote! {
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            const EXPECTED: &[&str] = &[#(#(#cfgs)* #labels),*];
+            #body
+        }
+    }
+}
+
+/// Generates visitors of integers, which recognize variants by their positions in the enum.
+fn get_visit_index(
+    ids: &[Ident],
+    cfgs: &[&Vec<Attribute>],
+    indexes: &[TokenStream],
+    dest_ident: &Ident,
+) -> TokenStream {
+    quote! {
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            match value {
+                #(
+                    #(#cfgs)*
+                    value if value == #indexes => Ok(#dest_ident::#ids),
+                )*
+                _ => Err(de::Error::invalid_value(de::Unexpected::Unsigned(value), &self)),
+            }
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            match u64::try_from(value) {
+                Ok(value) => self.visit_u64(value),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Signed(value), &self)),
+            }
+        }
+
+        fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+            match u64::try_from(value) {
+                Ok(value) => self.visit_u64(value),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Other("integer"), &self)),
+            }
+        }
+
+        fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+            match u64::try_from(value) {
+                Ok(value) => self.visit_u64(value),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Other("integer"), &self)),
+            }
+        }
+    }
+}
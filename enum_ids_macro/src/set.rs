@@ -0,0 +1,417 @@
+use crate::{case::Case, context::Context, get_indexes, variant::Variant};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Visibility};
+
+/// Generates `KindIdSet`, a bitset of the variants of the generated ID enum, its iterator and
+/// the construction macro `kind_id_set![A, B]`.
+///
+/// The set is backed by the smallest unsigned integer, which has a bit for every variant.
+/// The bit of a variant is its position in the enum.
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - The variants of the generated ID enum.
+/// * `dest_ident` - The name of the generated ID enum.
+/// * `visibility` - The visibility of the generated ID enum.
+///
+/// # Returns
+///
+/// * Generated code or an error if there are too many variants to fit into a bitset.
+pub fn get_set_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &Ident,
+    visibility: &Visibility,
+) -> Result<TokenStream, syn::Error> {
+    if !cx.set() {
+        return Ok(quote! {});
+    }
+    let bits = get_bits(variants.len(), dest_ident)?;
+    let set_ident = format_ident!("{}Set", dest_ident);
+    let iter_ident = format_ident!("{}SetIter", dest_ident);
+    let ids: Vec<Ident> = variants.iter().map(Variant::id_ident).collect();
+    let cfgs: Vec<&Vec<Attribute>> = variants.iter().map(|v| &v.cfg_attrs).collect();
+    let indexes = get_indexes(variants, &format_ident!("u32"));
+    let operations = get_operations(&set_ident);
+    let traits = get_traits(dest_ident, &set_ident, &iter_ident);
+    let construction = get_macro(dest_ident, &set_ident);
+    let iterator = get_iterator(dest_ident, &bits, &set_ident, &iter_ident, visibility);
+    Ok(quote! {
+        /// Set of IDs, which is backed by a bit mask.
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #visibility struct #set_ident {
+            bits: #bits,
+        }
+
+        impl #set_ident {
+            /// Mask with bits of all variants.
+            const MASK: #bits = match #bits::MAX.checked_shr(#bits::BITS - #dest_ident::COUNT as u32) {
+                Some(mask) => mask,
+                None => 0,
+            };
+
+            const fn bit(id: &#dest_ident) -> #bits {
+                match id {
+                    #(
+                        #(#cfgs)*
+                        #dest_ident::#ids => 1 << #indexes,
+                    )*
+                }
+            }
+
+            fn id(index: u32) -> #dest_ident {
+                match index {
+                    #(
+                        #(#cfgs)*
+                        index if index == #indexes => #dest_ident::#ids,
+                    )*
+                    _ => unreachable!("Invalid index of ID"),
+                }
+            }
+
+            /// Creates an empty set.
+            pub const fn new() -> Self {
+                Self { bits: 0 }
+            }
+
+            /// Creates a set, which contains all IDs.
+            pub const fn all() -> Self {
+                Self { bits: Self::MASK }
+            }
+
+            /// Returns the set with the given ID added. Useful in const contexts.
+            #[must_use]
+            pub const fn with(self, id: #dest_ident) -> Self {
+                Self {
+                    bits: self.bits | Self::bit(&id),
+                }
+            }
+
+            /// Returns the bit mask of the set. The bit of an ID is its position in the enum.
+            pub const fn bits(&self) -> #bits {
+                self.bits
+            }
+
+            /// Adds the ID to the set. Returns `true` if the ID wasn't present in the set.
+            pub fn insert(&mut self, id: #dest_ident) -> bool {
+                let bit = Self::bit(&id);
+                let inserted = self.bits & bit == 0;
+                self.bits |= bit;
+                inserted
+            }
+
+            /// Removes the ID from the set. Returns `true` if the ID was present in the set.
+            pub fn remove(&mut self, id: #dest_ident) -> bool {
+                let bit = Self::bit(&id);
+                let removed = self.bits & bit != 0;
+                self.bits &= !bit;
+                removed
+            }
+
+            /// Returns `true` if the set contains the ID.
+            pub const fn contains(&self, id: #dest_ident) -> bool {
+                self.bits & Self::bit(&id) != 0
+            }
+
+            /// Returns the number of IDs in the set.
+            pub const fn len(&self) -> usize {
+                self.bits.count_ones() as usize
+            }
+
+            /// Returns `true` if the set doesn't contain any ID.
+            pub const fn is_empty(&self) -> bool {
+                self.bits == 0
+            }
+
+            /// Removes all IDs from the set.
+            pub fn clear(&mut self) {
+                self.bits = 0;
+            }
+
+            /// Returns an iterator over IDs of the set in the order of declaration.
+            pub fn iter(&self) -> #iter_ident {
+                #iter_ident { bits: self.bits }
+            }
+        }
+
+        #operations
+
+        #traits
+
+        #iterator
+
+        #construction
+    })
+}
+
+/// Generates set operations: union, intersection, difference etc.
+fn get_operations(set_ident: &Ident) -> TokenStream {
+    quote! {
+        impl #set_ident {
+            /// Returns the set of IDs, which are present in `self` or in `other`.
+            #[must_use]
+            pub const fn union(self, other: Self) -> Self {
+                Self {
+                    bits: self.bits | other.bits,
+                }
+            }
+
+            /// Returns the set of IDs, which are present in both `self` and `other`.
+            #[must_use]
+            pub const fn intersection(self, other: Self) -> Self {
+                Self {
+                    bits: self.bits & other.bits,
+                }
+            }
+
+            /// Returns the set of IDs, which are present in `self`, but not in `other`.
+            #[must_use]
+            pub const fn difference(self, other: Self) -> Self {
+                Self {
+                    bits: self.bits & !other.bits,
+                }
+            }
+
+            /// Returns the set of IDs, which are present in only one of `self` and `other`.
+            #[must_use]
+            pub const fn symmetric_difference(self, other: Self) -> Self {
+                Self {
+                    bits: self.bits ^ other.bits,
+                }
+            }
+
+            /// Returns the set of IDs, which aren't present in `self`.
+            #[must_use]
+            pub const fn complement(self) -> Self {
+                Self {
+                    bits: !self.bits & Self::MASK,
+                }
+            }
+
+            /// Returns `true` if all IDs of `self` are present in `other`.
+            pub const fn is_subset(&self, other: &Self) -> bool {
+                self.bits & !other.bits == 0
+            }
+
+            /// Returns `true` if all IDs of `other` are present in `self`.
+            pub const fn is_superset(&self, other: &Self) -> bool {
+                other.is_subset(self)
+            }
+
+            /// Returns `true` if `self` and `other` don't have common IDs.
+            pub const fn is_disjoint(&self, other: &Self) -> bool {
+                self.bits & other.bits == 0
+            }
+        }
+    }
+}
+
+/// Generates implementations of standard traits for the set.
+fn get_traits(dest_ident: &Ident, set_ident: &Ident, iter_ident: &Ident) -> TokenStream {
+    quote! {
+        impl std::fmt::Debug for #set_ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                struct Name(&'static str);
+
+                impl std::fmt::Debug for Name {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str(self.0)
+                    }
+                }
+
+                f.debug_set()
+                    .entries(
+                        (0..#dest_ident::COUNT)
+                            .filter(|i| self.bits & (1 << i) != 0)
+                            .map(|i| Name(#dest_ident::NAMES[i])),
+                    )
+                    .finish()
+            }
+        }
+
+        impl std::iter::FromIterator<#dest_ident> for #set_ident {
+            fn from_iter<I: IntoIterator<Item = #dest_ident>>(iter: I) -> Self {
+                let mut set = Self::new();
+                set.extend(iter);
+                set
+            }
+        }
+
+        impl std::iter::Extend<#dest_ident> for #set_ident {
+            fn extend<I: IntoIterator<Item = #dest_ident>>(&mut self, iter: I) {
+                for id in iter {
+                    self.insert(id);
+                }
+            }
+        }
+
+        impl std::iter::IntoIterator for #set_ident {
+            type Item = #dest_ident;
+            type IntoIter = #iter_ident;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl std::iter::IntoIterator for &#set_ident {
+            type Item = #dest_ident;
+            type IntoIter = #iter_ident;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl From<#dest_ident> for #set_ident {
+            fn from(id: #dest_ident) -> Self {
+                Self::new().with(id)
+            }
+        }
+
+        impl std::ops::BitOr for #set_ident {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                self.union(other)
+            }
+        }
+
+        impl std::ops::BitOrAssign for #set_ident {
+            fn bitor_assign(&mut self, other: Self) {
+                *self = self.union(other);
+            }
+        }
+
+        impl std::ops::BitAnd for #set_ident {
+            type Output = Self;
+
+            fn bitand(self, other: Self) -> Self {
+                self.intersection(other)
+            }
+        }
+
+        impl std::ops::BitAndAssign for #set_ident {
+            fn bitand_assign(&mut self, other: Self) {
+                *self = self.intersection(other);
+            }
+        }
+
+        impl std::ops::Sub for #set_ident {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                self.difference(other)
+            }
+        }
+
+        impl std::ops::SubAssign for #set_ident {
+            fn sub_assign(&mut self, other: Self) {
+                *self = self.difference(other);
+            }
+        }
+
+        impl std::ops::Not for #set_ident {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                self.complement()
+            }
+        }
+    }
+}
+
+/// Generates the iterator over IDs of the set.
+fn get_iterator(
+    dest_ident: &Ident,
+    bits: &Ident,
+    set_ident: &Ident,
+    iter_ident: &Ident,
+    visibility: &Visibility,
+) -> TokenStream {
+    quote! {
+        /// Iterator over IDs of a set in the order of declaration.
+        #[derive(Clone, Debug)]
+        #visibility struct #iter_ident {
+            bits: #bits,
+        }
+
+        impl Iterator for #iter_ident {
+            type Item = #dest_ident;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.bits == 0 {
+                    return None;
+                }
+                let index = self.bits.trailing_zeros();
+                self.bits &= self.bits - 1;
+                Some(#set_ident::id(index))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.bits.count_ones() as usize;
+                (len, Some(len))
+            }
+        }
+
+        impl DoubleEndedIterator for #iter_ident {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.bits == 0 {
+                    return None;
+                }
+                let index = #bits::BITS - 1 - self.bits.leading_zeros();
+                self.bits &= !(1 << index);
+                Some(#set_ident::id(index))
+            }
+        }
+
+        impl ExactSizeIterator for #iter_ident {}
+
+        impl std::iter::FusedIterator for #iter_ident {}
+    }
+}
+
+/// Generates the construction macro `kind_id_set![A, B]`.
+///
+/// The macro isn't exported, because the enum may be declared in any module and `$crate` only
+/// refers to the root of the crate. It refers to the set and the ID enum by name, so they must be
+/// in scope where the macro is used.
+fn get_macro(dest_ident: &Ident, set_ident: &Ident) -> TokenStream {
+    let macro_ident = format_ident!("{}_set", Case::Snake.convert(dest_ident.to_string()));
+    quote! {
+        /// Creates a set of IDs from the names of variants, e.g. `kind_id_set![A, B]`.
+        ///
+        /// The macro is local to the module of the enum; the set and the ID enum must be in scope.
+        #[allow(unused_macros)]
+        macro_rules! #macro_ident {
+            () => {
+                #set_ident::new()
+            };
+            ($($id:ident),+ $(,)?) => {
+                #set_ident::new()$(.with(#dest_ident::$id))+
+            };
+        }
+    }
+}
+
+/// Returns the smallest unsigned integer type, which has a bit for every variant.
+fn get_bits(count: usize, dest_ident: &Ident) -> Result<Ident, syn::Error> {
+    Ok(Ident::new(
+        match count {
+            0..=8 => "u8",
+            9..=16 => "u16",
+            17..=32 => "u32",
+            33..=64 => "u64",
+            65..=128 => "u128",
+            _ => {
+                return Err(syn::Error::new(
+                    dest_ident.span(),
+                    format!("Set cannot hold {count} variants; maximum is 128 variants"),
+                ));
+            }
+        },
+        Span::call_site(),
+    ))
+}
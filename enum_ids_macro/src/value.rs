@@ -0,0 +1,290 @@
+use crate::{context::Context, get_source_methods, predicate, variant::Variant};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{self, Parse, ParseStream},
+    punctuated::Punctuated,
+    Expr, Ident, ItemEnum, Token, Type,
+};
+
+/// Name of the attribute, which declares typed values: `value(status: u16 = 404)`.
+const VALUE: &str = "value";
+
+/// Represents a typed value of a variant or a declaration of a value on the level of the enum,
+/// e.g. `status: u16 = 404`.
+#[derive(Clone, Debug)]
+pub struct Value {
+    /// The name of the value, which is also the name of the generated method.
+    pub name: Ident,
+    /// The type of the value, e.g. `u16`.
+    pub ty: Option<TokenStream>,
+    /// The value; on the level of the enum it's the default value.
+    pub expr: Option<TokenStream>,
+}
+
+impl Parse for Value {
+    /// Parses a value like `name: Type = expr`; the type and the expression are optional.
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let name: Ident = input.parse()?;
+        let ty = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            Some(input.parse::<Type>()?.to_token_stream())
+        } else {
+            None
+        };
+        let expr = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse::<Expr>()?.to_token_stream())
+        } else {
+            None
+        };
+        Ok(Value { name, ty, expr })
+    }
+}
+
+/// Represents an argument of `#[enum_ids(...)]`: either a list of typed values `value(...)`
+/// or any other attribute, which is parsed as an expression.
+pub enum Arg {
+    /// Typed values `value(name: Type = expr, ...)` together with the identifier `value`.
+    Values(Ident, Vec<Value>),
+    /// Any other attribute.
+    Expr(Expr),
+}
+
+impl Parse for Arg {
+    /// Parses an argument of `#[enum_ids(...)]`.
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let fork = input.fork();
+        if fork.parse::<Ident>().is_ok_and(|ident| ident == VALUE) && fork.peek(syn::token::Paren) {
+            let ident = input.parse::<Ident>()?;
+            let content;
+            parenthesized!(content in input);
+            let values = Punctuated::<Value, Token![,]>::parse_terminated(&content)?;
+            Ok(Arg::Values(ident, values.into_iter().collect()))
+        } else {
+            Ok(Arg::Expr(input.parse()?))
+        }
+    }
+}
+
+/// Methods of the generated ID enum, which are generated independently of directives.
+const RESERVED: &[&str] = &["as_vec", "deprecation", "is_deprecated", "deprecation_note"];
+
+/// Methods of the generated ID enum, which are generated by `ordinal`.
+const ORDINAL: &[&str] = &[
+    "index",
+    "from_index",
+    "next",
+    "prev",
+    "next_cyclic",
+    "prev_cyclic",
+    "range",
+];
+
+/// Represents a value declared for the enum: its name, type and default value.
+struct Declaration<'a> {
+    name: &'a Ident,
+    ty: &'a TokenStream,
+    default: Option<&'a TokenStream>,
+}
+
+/// Collects the declarations of values from the enum and its variants.
+///
+/// A value is declared by the enum (`#[enum_ids(value(status: u16 = 500))]`) or by a variant,
+/// which defines the type of the value. The type of a value must be the same everywhere.
+fn get_declarations<'a>(
+    cx: &'a Context,
+    variants: &'a [Variant],
+) -> Result<Vec<Declaration<'a>>, syn::Error> {
+    let mut declarations: Vec<Declaration> = Vec::new();
+    let enum_values = cx.values().map(|value| (value, true));
+    let variant_values = variants
+        .iter()
+        .flat_map(Variant::values)
+        .map(|value| (value, false));
+    for (value, is_default) in enum_values.chain(variant_values) {
+        let default = value.expr.as_ref().filter(|_| is_default);
+        if let Some(declaration) = declarations.iter().find(|d| *d.name == value.name) {
+            if let Some(ty) = &value.ty {
+                if ty.to_string() != declaration.ty.to_string() {
+                    return Err(syn::Error::new(
+                        value.name.span(),
+                        format!(
+                            "Value \"{}\" is declared with type \"{}\", but \"{ty}\" is used",
+                            value.name, declaration.ty
+                        ),
+                    ));
+                }
+            }
+            if is_default {
+                return Err(syn::Error::new(
+                    value.name.span(),
+                    format!("Value \"{}\" is declared twice", value.name),
+                ));
+            }
+        } else if let Some(ty) = &value.ty {
+            declarations.push(Declaration {
+                name: &value.name,
+                ty,
+                default,
+            });
+        } else {
+            return Err(syn::Error::new(
+                value.name.span(),
+                format!(
+                    "Type of value \"{}\" isn't defined; use \"{}: Type = ...\"",
+                    value.name, value.name
+                ),
+            ));
+        }
+    }
+    Ok(declarations)
+}
+
+/// Returns the names of all values of the enum and its variants, i.e. the names of the methods
+/// generated for the values.
+pub fn get_names<'a>(
+    cx: &'a Context,
+    variants: &'a [Variant],
+) -> impl Iterator<Item = String> + 'a {
+    cx.values()
+        .chain(variants.iter().flat_map(Variant::values))
+        .map(|value| value.name.to_string())
+}
+
+/// Checks that the declared values don't have names of other generated methods of the ID enum
+/// and of the source enum.
+fn check_names(
+    cx: &Context,
+    variants: &[Variant],
+    declarations: &[Declaration],
+    dest_ident: &Ident,
+    src: &Ident,
+) -> Result<(), syn::Error> {
+    let mut reserved: Vec<String> = RESERVED.iter().map(|name| String::from(*name)).collect();
+    if cx.ordinal() {
+        reserved.extend(ORDINAL.iter().map(|name| String::from(*name)));
+    }
+    if cx.iter_required() {
+        reserved.push(cx.iter_name(dest_ident).to_string());
+    }
+    if let Some(ty) = cx.repr(variants.iter().filter(|v| !v.skip()).count()) {
+        reserved.push(format!("as_{ty}"));
+    }
+    reserved.extend(get_source_methods(cx, variants, src));
+    reserved.extend(predicate::get_names(cx, variants));
+    for declaration in declarations {
+        let name = declaration.name.to_string();
+        if reserved.contains(&name) {
+            return Err(syn::Error::new(
+                declaration.name.span(),
+                format!("Value \"{name}\" conflicts with the generated method"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Generates a `const fn` for every declared value on the generated ID enum and on the source enum.
+///
+/// # Arguments
+///
+/// * `cx` - The context of the macro.
+/// * `variants` - All variants of the source enum.
+/// * `dest_ident` - The name of the generated ID enum.
+/// * `input` - The source enum.
+///
+/// # Returns
+///
+/// * Generated code or an error, if a variant doesn't define a value without a default value or
+///   a value has the name of another generated method.
+pub fn get_values_impl(
+    cx: &Context,
+    variants: &[Variant],
+    dest_ident: &Ident,
+    input: &ItemEnum,
+) -> Result<TokenStream, syn::Error> {
+    let declarations = get_declarations(cx, variants)?;
+    if declarations.is_empty() {
+        return Ok(quote! {});
+    }
+    for variant in variants {
+        for value in variant.values() {
+            if value.expr.is_none() {
+                return Err(syn::Error::new(
+                    value.name.span(),
+                    format!("Value \"{}\" of the variant isn't defined", value.name),
+                ));
+            }
+        }
+    }
+    let src = &input.ident;
+    check_names(cx, variants, &declarations, dest_ident, src)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut id_methods = Vec::new();
+    let mut src_methods = Vec::new();
+    for declaration in &declarations {
+        let name = declaration.name;
+        let ty = declaration.ty;
+        let doc = format!(" Returns the value \"{name}\" of the variant.");
+        let mut id_arms = Vec::new();
+        let mut src_arms = Vec::new();
+        for variant in variants {
+            let expr = variant
+                .values()
+                .find(|value| value.name == *name)
+                .and_then(|value| value.expr.as_ref())
+                .or(declaration.default)
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        variant.ident.span(),
+                        format!(
+                            "Variant \"{}\" doesn't define value \"{name}\", which doesn't have a default value",
+                            variant.ident
+                        ),
+                    )
+                })?;
+            let cfg = &variant.cfg_attrs;
+            let pattern = variant.pattern(src);
+            src_arms.push(quote! { #(#cfg)* #pattern => #expr, });
+            if !variant.skip() {
+                let id_ident = variant.id_ident();
+                id_arms.push(quote! { #(#cfg)* #dest_ident::#id_ident => #expr, });
+            }
+        }
+        id_methods.push(quote! {
+            #[doc = #doc]
+            pub const fn #name(&self) -> #ty {
+                match self {
+                    #(#id_arms)*
+                }
+            }
+        });
+        src_methods.push(quote! {
+            #[doc = #doc]
+            pub const fn #name(&self) -> #ty {
+                match self {
+                    #(#src_arms)*
+                }
+            }
+        });
+    }
+    Ok(quote! {
+        impl #dest_ident {
+            #(#id_methods)*
+        }
+
+        impl #impl_generics #src #ty_generics #where_clause {
+            #(#src_methods)*
+        }
+    })
+}
+
+/// Returns an error for `value` used without a list of values.
+pub fn get_value_error(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!("Expecting list of values like {VALUE}(name: Type = value)"),
+    )
+}
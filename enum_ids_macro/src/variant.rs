@@ -0,0 +1,362 @@
+use crate::{
+    attr, check_combinations, check_ident, combine_errors, get_ident, get_str_value, value,
+};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::convert::TryFrom;
+use syn::{
+    parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprLit, Fields, Ident,
+    ItemEnum, Lit, Meta, MetaNameValue, Token, Type,
+};
+
+/// Name of the helper attribute, which can be applied to the variants of the source enum.
+const HELPER: &str = "enum_ids";
+
+/// Represents a variant of the source enum together with the attributes applied to it.
+#[derive(Clone)]
+pub struct Variant {
+    /// The identifier of the source variant.
+    pub ident: Ident,
+    /// The fields of the source variant.
+    pub fields: Fields,
+    /// A list of attributes applied to the variant.
+    pub attrs: Vec<attr::VariantAttr>,
+    /// The doc comments of the source variant.
+    pub doc_attrs: Vec<Attribute>,
+    /// The `#[cfg(...)]` attributes of the source variant.
+    pub cfg_attrs: Vec<Attribute>,
+    /// The `#[deprecated]` attributes of the source variant.
+    pub deprecated_attrs: Vec<Attribute>,
+}
+
+impl Variant {
+    /// Extracts the variants of the source enum with their attributes.
+    ///
+    /// The helper attributes `#[enum_ids(...)]` are removed from the source enum, because it's
+    /// emitted back as it is and the compiler doesn't know anything about these attributes.
+    ///
+    /// Invalid attributes are skipped and their errors are added to `errors`, so that they are
+    /// reported together with the errors of the enum. Attributes defined more than once (except
+    /// `value(...)`) and contradictory attributes like `skip` with `rename` are reported as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The source enum.
+    /// * `errors` - The errors found so far.
+    ///
+    /// # Returns
+    ///
+    /// * The list of variants.
+    pub fn collect(input: &mut ItemEnum, errors: &mut Vec<syn::Error>) -> Vec<Variant> {
+        let mut variants = Vec::new();
+        for variant in &mut input.variants {
+            let mut attrs = Vec::new();
+            for helper in variant
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident(HELPER))
+            {
+                match Variant::parse(helper) {
+                    Ok(parsed) => attrs.extend(parsed),
+                    Err(error) => errors.push(error),
+                }
+            }
+            let (attrs, spans): (Vec<attr::VariantAttr>, Vec<Span>) = attrs.into_iter().unzip();
+            let names: Vec<String> = attrs.iter().map(ToString::to_string).collect();
+            errors.extend(check_combinations(
+                &names,
+                &spans,
+                attr::VARIANT_LISTS,
+                attr::VARIANT_CONFLICTS,
+            ));
+            variant.attrs.retain(|attr| !attr.path().is_ident(HELPER));
+            let variant = Variant {
+                ident: variant.ident.clone(),
+                fields: variant.fields.clone(),
+                attrs,
+                doc_attrs: variant
+                    .attrs
+                    .iter()
+                    .filter(|attr| attr.path().is_ident("doc"))
+                    .cloned()
+                    .collect(),
+                cfg_attrs: variant
+                    .attrs
+                    .iter()
+                    .filter(|attr| attr.path().is_ident("cfg"))
+                    .cloned()
+                    .collect(),
+                deprecated_attrs: variant
+                    .attrs
+                    .iter()
+                    .filter(|attr| attr.path().is_ident("deprecated"))
+                    .cloned()
+                    .collect(),
+            };
+            for attr in &variant.cfg_attrs {
+                if let Err(error) = attr.meta.require_list() {
+                    errors.push(error);
+                }
+            }
+            if variant.nested() && variant.nested_ty().is_none() {
+                errors.push(syn::Error::new(
+                    variant.ident.span(),
+                    "Attribute \"nested\" can be applied only to a variant with a single unnamed field",
+                ));
+            }
+            variants.push(variant);
+        }
+        variants
+    }
+
+    /// Parses a single `#[enum_ids(...)]` attribute of the variant.
+    ///
+    /// The expected input can include:
+    /// - Attributes in the form of `key = "value"` or `key = value`
+    /// - Expressions like `sample = Kind::A(1)`
+    /// - Standalone attributes like `skip`
+    /// - Values like `value(status: u16 = 404)`
+    fn parse(helper: &Attribute) -> syn::Result<Vec<(attr::VariantAttr, Span)>> {
+        let mut attrs = Vec::new();
+        let mut errors = Vec::new();
+        for arg in helper.parse_args_with(Punctuated::<value::Arg, Token![,]>::parse_terminated)? {
+            match Variant::parse_arg(arg) {
+                Ok(attr) => attrs.push(attr),
+                Err(error) => errors.push(error),
+            }
+        }
+        combine_errors(errors)?;
+        Ok(attrs)
+    }
+
+    /// Parses a single argument of `#[enum_ids(...)]` of the variant into the attribute and the
+    /// span of its key.
+    fn parse_arg(arg: value::Arg) -> syn::Result<(attr::VariantAttr, Span)> {
+        let expr = match arg {
+            value::Arg::Values(ident, values) => {
+                return Ok((attr::VariantAttr::Value(values), ident.span()));
+            }
+            value::Arg::Expr(expr) => expr,
+        };
+        let a = match expr {
+            Expr::Assign(a) => a,
+            Expr::Path(p) => {
+                let Some(ident) = p.path.get_ident() else {
+                    return Err(syn::Error::new_spanned(p, "Cannot extract identifier"));
+                };
+                let attr =
+                    attr::VariantAttr::try_from(ident.to_string().as_ref()).map_err(|e| {
+                        syn::Error::new(
+                            ident.span(),
+                            format!("Cannot parse attribute \"{ident}\": {e}"),
+                        )
+                    })?;
+                if matches!(attr, attr::VariantAttr::Value(..)) {
+                    return Err(value::get_value_error(ident.span()));
+                }
+                if !matches!(attr, attr::VariantAttr::Skip | attr::VariantAttr::Nested) {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("Attribute \"{ident}\" requires a value"),
+                    ));
+                }
+                return Ok((attr, ident.span()));
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "Expecting expression like [key = \"value as String\"] or [key]",
+                ));
+            }
+        };
+        if let Expr::Path(left) = a.left.as_ref() {
+            if left
+                .path
+                .is_ident(&attr::VariantAttr::Sample(TokenStream::new()).to_string())
+            {
+                return Ok((
+                    attr::VariantAttr::Sample(a.right.to_token_stream()),
+                    left.path.span(),
+                ));
+            }
+        }
+        let (Expr::Path(left), Some(value)) = (a.left.as_ref(), get_str_value(&a.right)) else {
+            return Err(syn::Error::new(
+                a.eq_token.span,
+                "Expecting expression like key = \"value as String\" or key = value",
+            ));
+        };
+        let Some(left) = left.path.get_ident() else {
+            return Err(syn::Error::new_spanned(left, "Cannot extract identifier"));
+        };
+        let attr = attr::VariantAttr::try_from(left.to_string().as_ref()).map_err(|e| {
+            syn::Error::new(
+                left.span(),
+                format!("Cannot parse attribute \"{left}\": {e}"),
+            )
+        })?;
+        let attr = match attr {
+            attr::VariantAttr::Rename(..) => attr::VariantAttr::Rename(value.value()),
+            attr::VariantAttr::RenameId(..) => {
+                check_ident(&value)?;
+                attr::VariantAttr::RenameId(value.value())
+            }
+            attr::VariantAttr::Sample(..) => attr::VariantAttr::Sample(value.to_token_stream()),
+            attr::VariantAttr::Value(..) => return Err(value::get_value_error(left.span())),
+            attr::VariantAttr::Skip | attr::VariantAttr::Nested => {
+                return Err(syn::Error::new(
+                    left.span(),
+                    format!("Attribute \"{left}\" cannot have a value"),
+                ));
+            }
+        };
+        Ok((attr, left.span()))
+    }
+
+    /// Determines the variant is excluded from the generated enum
+    pub fn skip(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::VariantAttr::Skip))
+    }
+
+    /// Determines the variant wraps another enum with IDs
+    pub fn nested(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|at| matches!(at, attr::VariantAttr::Nested))
+    }
+
+    /// Returns the type of the single unnamed field of the variant.
+    pub fn nested_ty(&self) -> Option<&Type> {
+        match &self.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                fields.unnamed.first().map(|field| &field.ty)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the expression, which creates a sample of the variant for `iterator`.
+    pub fn sample(&self) -> Option<&TokenStream> {
+        self.attrs.iter().find_map(|at| {
+            if let attr::VariantAttr::Sample(expr) = at {
+                Some(expr)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the values of the variant defined with `value(...)`.
+    pub fn values(&self) -> impl Iterator<Item = &value::Value> {
+        self.attrs.iter().flat_map(|at| {
+            if let attr::VariantAttr::Value(values) = at {
+                values.as_slice()
+            } else {
+                &[]
+            }
+        })
+    }
+
+    /// Returns the pattern, which matches the variant of the source enum regardless of its fields.
+    pub fn pattern(&self, src: &Ident) -> TokenStream {
+        let ident = &self.ident;
+        match &self.fields {
+            Fields::Unit => quote! { #src::#ident },
+            Fields::Unnamed(_) => quote! { #src::#ident(..) },
+            Fields::Named(_) => quote! { #src::#ident{..} },
+        }
+    }
+
+    /// Returns the string, which replaces the name of the variant in display and parse implementations.
+    pub fn rename(&self) -> Option<&str> {
+        self.attrs.iter().find_map(|at| {
+            if let attr::VariantAttr::Rename(name) = at {
+                Some(name.as_str())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the condition of `#[cfg(...)]` attributes of the variant as an argument of `cfg!()`.
+    ///
+    /// Returns `None` if the variant is always present.
+    pub fn cfg_predicate(&self) -> Option<TokenStream> {
+        if self.cfg_attrs.is_empty() {
+            return None;
+        }
+        let predicates = self
+            .cfg_attrs
+            .iter()
+            .filter_map(|attr| attr.meta.require_list().ok())
+            .map(|list| &list.tokens);
+        Some(quote! { all(#(#predicates),*) })
+    }
+
+    /// Determines the variant is marked with `#[deprecated]`
+    pub fn deprecated(&self) -> bool {
+        !self.deprecated_attrs.is_empty()
+    }
+
+    /// Returns the note of `#[deprecated]` of the variant, which is defined either as
+    /// `#[deprecated = "..."]` or as `#[deprecated(note = "...")]`.
+    pub fn deprecation_note(&self) -> Option<String> {
+        self.deprecated_attrs
+            .iter()
+            .find_map(|attr| match &attr.meta {
+                Meta::NameValue(MetaNameValue {
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(note),
+                            ..
+                        }),
+                    ..
+                }) => Some(note.value()),
+                Meta::List(list) => list
+                    .parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)
+                    .ok()?
+                    .into_iter()
+                    .find(|pair| pair.path.is_ident("note"))
+                    .and_then(|pair| match pair.value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(note),
+                            ..
+                        }) => Some(note.value()),
+                        _ => None,
+                    }),
+                Meta::Path(_) | Meta::NameValue(_) => None,
+            })
+    }
+
+    /// Returns the doc comments for the variant of the generated ID enum.
+    ///
+    /// The doc comments of the source variant are used. If the source variant isn't documented,
+    /// a default doc comment is generated.
+    pub fn id_docs(&self, src: &Ident) -> Vec<Attribute> {
+        if self.doc_attrs.is_empty() {
+            let doc = format!(" ID of `{src}::{}`.", self.ident);
+            vec![parse_quote! { #[doc = #doc] }]
+        } else {
+            self.doc_attrs.clone()
+        }
+    }
+
+    /// Determines the identifier of the variant in the generated ID enum.
+    ///
+    /// If a `RenameId` attribute is present, its value is used.
+    /// Otherwise, the identifier of the source variant is used.
+    pub fn id_ident(&self) -> Ident {
+        self.attrs
+            .iter()
+            .find_map(|at| {
+                if let attr::VariantAttr::RenameId(name) = at {
+                    Some(get_ident(name, self.ident.span()))
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| self.ident.clone())
+    }
+}
@@ -7,3 +7,15 @@ fn pass() {
 fn compile_fail() {
     trybuild::TestCases::new().compile_fail("./src/tests/ui/compile_fail/*.rs");
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn pass_serde() {
+    trybuild::TestCases::new().pass("./src/tests/ui/serde/*.rs");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn compile_fail_serde() {
+    trybuild::TestCases::new().compile_fail("./src/tests/ui/serde_compile_fail/*.rs");
+}
@@ -0,0 +1,9 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(accessors)]
+pub enum Kind {
+    A(i32),
+    AMut(i32),
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(accessors, iterator)]
+pub enum Kind {
+    A(i32),
+    Vec(u8),
+}
+
+#[enum_ids(accessors, getter = "as_kind")]
+pub enum Getter {
+    A(i32),
+    Kind(u8),
+}
+
+#[enum_ids(accessors, value(into_code: u8 = 0))]
+pub enum Value {
+    A(i32),
+    Code(u8),
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(display_varaint)]
+pub enum Kind {
+    #[enum_ids(skp)]
+    A,
+}
+
+#[enum_ids(public, not_public)]
+pub enum Visibility {
+    #[enum_ids(renam = "a")]
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use enum_ids::enum_ids;
+
+#[enum_ids]
+pub enum Kind {
+    #[enum_ids(skip)]
+    A,
+    #[enum_ids(skip)]
+    B(i32),
+}
+
+#[enum_ids]
+pub enum Empty {}
+
+fn main() {}
@@ -0,0 +1,40 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(public, not_public)]
+pub enum Visibility {
+    A,
+}
+
+#[enum_ids(display, parse, display_variant)]
+pub enum Display {
+    A,
+}
+
+#[enum_ids(name = "First", getter = "get", name = "Second")]
+pub enum Duplicate {
+    A,
+}
+
+#[enum_ids(display_variant_snake, rename_all = "kebab-case")]
+pub enum Case {
+    A,
+}
+
+#[enum_ids(serde_any)]
+pub enum Serde {
+    A,
+}
+
+#[enum_ids(iter_all = "AllKinds")]
+pub enum IterAll {
+    A,
+}
+
+#[enum_ids(display_from_value)]
+pub enum FromValue {
+    A(i32),
+    B,
+    C(i32, i32),
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(repr, attrs(non_exhaustive, repr(u16)))]
+pub enum Explicit {
+    A,
+}
+
+#[enum_ids(repr = "u8", inherit_attrs = "repr")]
+#[repr(u8)]
+pub enum Inherited {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(value(code: u8 = 0))]
+pub enum Kind {
+    #[enum_ids(rename = "a", rename = "b")]
+    A,
+    #[enum_ids(rename_id = "First")]
+    #[enum_ids(rename_id = "Second")]
+    B,
+    #[enum_ids(skip, rename = "c")]
+    C,
+    #[enum_ids(rename_id = "Other", skip)]
+    D,
+    #[enum_ids(skip, sample = Kind::F(1))]
+    F(i32),
+    #[enum_ids(skip, nested)]
+    G(Inner),
+}
+
+#[enum_ids]
+pub enum Inner {
+    X,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use enum_ids::enum_ids;
+
+#[enum_ids]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Kind {
+    Current(i32),
+    #[deprecated(note = "use Current")]
+    Legacy(i32),
+}
+
+fn main() {
+    let _ = KindId::Legacy;
+}
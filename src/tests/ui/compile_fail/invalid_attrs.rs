@@ -0,0 +1,27 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(attrs(derive(Hash)))]
+pub enum Kind {
+    A(i32),
+    B,
+}
+
+#[enum_ids(attrs(1 + 2))]
+pub enum Other {
+    A(i32),
+    B,
+}
+
+#[enum_ids(inherit_attrs = "serde, derive")]
+pub enum Inherit {
+    A(i32),
+    B,
+}
+
+#[enum_ids(getter("id"))]
+pub enum Call {
+    A(i32),
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(name = "123Kind")]
+pub enum Name {
+    A,
+}
+
+#[enum_ids(getter = "get-id", iter = "self")]
+pub enum Getter {
+    A,
+}
+
+#[enum_ids(getter = "id½")]
+pub enum Fraction {
+    A,
+}
+
+#[enum_ids(derive = "Debug, 1x")]
+pub enum Derive {
+    A,
+}
+
+#[enum_ids(display_varaint, name = "", no_deriv)]
+pub enum Misspelled {
+    A,
+}
+
+#[enum_ids]
+pub enum Variant {
+    #[enum_ids(rename_id = "B-1", skp)]
+    A,
+    #[enum_ids(rename_id = "crate")]
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_ids::enum_ids;
+
+#[enum_ids]
+pub enum Event {
+    #[enum_ids(nested)]
+    Net { code: i32 },
+    Tick,
+}
+
+fn main() {}
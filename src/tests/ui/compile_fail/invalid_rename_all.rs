@@ -0,0 +1,9 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(rename_all = "snake")]
+pub enum Kind {
+    A(i32),
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(repr = "f32")]
+pub enum Kind {
+    A(i32),
+    B { value: String },
+    C,
+}
+
+fn main() {}
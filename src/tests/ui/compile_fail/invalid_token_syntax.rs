@@ -0,0 +1,28 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(derive(Debug, 1 + 2), getter = get::id)]
+pub enum Derive {
+    A,
+}
+
+#[enum_ids(display(variant, unknown), public(crate))]
+pub enum Group {
+    A,
+}
+
+#[enum_ids(display(variant, variant_snake))]
+pub enum Modes {
+    A(i32),
+}
+
+#[enum_ids(derive(Debug, Clonee))]
+pub enum Misspelled {
+    A,
+}
+
+#[enum_ids(display(public, set), parse(variant))]
+pub enum Foreign {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enum_ids::enum_ids;
+
+#[enum_ids]
+pub enum Kind {
+    A(i32),
+    #[enum_ids(rename_id = "1B")]
+    B { value: String },
+    #[enum_ids(unknown = "value")]
+    C,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use enum_ids::enum_ids;
+
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[enum_ids(iterator)]
+pub enum Kind {
+    A(i32),
+    B { point: Point },
+}
+
+fn main() {}
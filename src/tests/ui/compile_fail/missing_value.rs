@@ -0,0 +1,23 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(value(status: u16, retryable: bool = false))]
+pub enum Error {
+    #[enum_ids(value(status = 404))]
+    NotFound,
+    #[enum_ids(value(retryable = true))]
+    Unavailable,
+}
+
+#[enum_ids]
+pub enum Untyped {
+    #[enum_ids(value(status = 404))]
+    NotFound,
+}
+
+#[enum_ids(value(status: u16))]
+pub enum Mismatch {
+    #[enum_ids(value(status: u32 = 404))]
+    NotFound,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(rename_all = "snake_case")]
+pub enum Kind {
+    FieldA(i32),
+    Field_A,
+    C,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use enum_ids::enum_ids;
+
+#[enum_ids]
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetKind {
+    Connect,
+    #[enum_ids(skip)]
+    Internal,
+}
+
+#[enum_ids]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    #[enum_ids(nested)]
+    Net(NetKind),
+    Tick,
+}
+
+fn main() {}
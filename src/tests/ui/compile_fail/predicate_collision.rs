@@ -0,0 +1,15 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(predicates)]
+pub enum Kind {
+    HttpServer,
+    HTTPServer,
+}
+
+#[enum_ids(predicates)]
+pub enum Other {
+    A,
+    OneOf,
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(ordinal, value(index: u8 = 0))]
+pub enum Ordinal {
+    A,
+    B,
+}
+
+#[enum_ids(iter)]
+pub enum Iter {
+    #[enum_ids(value(iter: u8 = 1))]
+    A,
+}
+
+#[enum_ids(value(is_deprecated: bool = false))]
+pub enum Deprecated {
+    A,
+}
+
+#[enum_ids(repr, value(as_u8: u8 = 0))]
+pub enum Repr {
+    A,
+}
+
+#[enum_ids(getter = "kind", value(kind: u8 = 0))]
+pub enum Getter {
+    A,
+}
+
+#[enum_ids(predicates)]
+pub enum Predicate {
+    A,
+    #[enum_ids(value(is_a: bool = true))]
+    B,
+}
+
+fn main() {}
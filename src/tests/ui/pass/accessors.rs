@@ -0,0 +1,47 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(accessors)]
+#[derive(Debug, PartialEq)]
+pub enum Kind<T> {
+    Single(T),
+    Pair(i32, String),
+    Point { x: i32, y: i32 },
+    Named { r#type: String },
+    #[cfg(any())]
+    Hidden(u8),
+    Empty,
+}
+
+#[enum_ids(accessors)]
+#[derive(Debug, PartialEq)]
+pub enum Only {
+    Value(u8),
+}
+
+const SINGLE: Option<&u8> = Kind::Single(5u8).as_single();
+
+fn main() {
+    assert_eq!(SINGLE, Some(&5));
+    let mut pair: Kind<u8> = Kind::Pair(1, String::from("a"));
+    assert_eq!(pair.as_pair(), Some((&1, &String::from("a"))));
+    assert_eq!(pair.as_single(), None);
+    if let Some((number, text)) = pair.as_pair_mut() {
+        *number = 2;
+        text.push('b');
+    }
+    assert_eq!(pair.into_pair(), Ok((2, String::from("ab"))));
+
+    let point: Kind<u8> = Kind::Point { x: 1, y: 2 };
+    assert_eq!(point.as_point(), Some((&1, &2)));
+    let point = point.into_single().unwrap_err();
+    assert_eq!(point.into_point(), Ok((1, 2)));
+
+    let named: Kind<u8> = Kind::Named {
+        r#type: String::from("t"),
+    };
+    assert_eq!(named.as_named(), Some(&String::from("t")));
+    assert_eq!(Kind::<u8>::Empty.into_named(), Err(Kind::Empty));
+
+    assert_eq!(Only::Value(3).into_value(), Ok(3));
+    assert_eq!(Only::Value(3).as_value(), Some(&3));
+}
@@ -0,0 +1,44 @@
+use enum_ids::enum_ids;
+use serde::Serialize;
+
+#[enum_ids(
+    derive = "Debug, Clone, Copy, PartialEq, Eq, Serialize",
+    attrs(
+        non_exhaustive,
+        repr(u8),
+        serde(rename_all = "kebab-case"),
+        cfg_attr(all(), derive(Hash)),
+        cfg_attr(any(), derive(Default)),
+        allow(clippy::upper_case_acronyms)
+    )
+)]
+pub enum Kind {
+    FieldA(i32),
+    HTTPServer { value: String },
+}
+
+#[enum_ids(inherit_attrs = "serde, allow")]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Message {
+    PingRequest(u8),
+    ACK,
+}
+
+fn main() {
+    assert_eq!(KindId::HTTPServer as u8, 1);
+    assert_eq!(
+        serde_json::to_string(&KindId::HTTPServer).unwrap(),
+        r#""h-t-t-p-server""#
+    );
+    let mut set = std::collections::HashSet::new();
+    set.insert(KindId::FieldA);
+    assert!(set.contains(&KindId::FieldA));
+
+    assert_eq!(
+        serde_json::to_string(&MessageId::PingRequest).unwrap(),
+        r#""ping_request""#
+    );
+    assert_eq!(serde_json::to_string(&MessageId::ACK).unwrap(), r#""a_c_k""#);
+}
@@ -0,0 +1,64 @@
+use enum_ids::{enum_ids, IdEnum};
+use std::convert::TryFrom;
+
+#[enum_ids(display_variant_snake, iterator, parse, repr, set, map)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA,
+    #[cfg(any())]
+    FieldB,
+    FieldC,
+    #[cfg(all())]
+    #[cfg(not(any()))]
+    FieldD,
+    #[cfg(any())]
+    FieldE,
+}
+
+#[enum_ids(display_variant, parse_ignore_case)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Event {
+    #[enum_ids(nested)]
+    Inner(Kind),
+    #[cfg(any())]
+    Hidden(Kind),
+    Tick(u32),
+}
+
+fn main() {
+    assert_eq!(KindId::COUNT, 3);
+    assert_eq!(<KindId as IdEnum>::COUNT, 3);
+    assert_eq!(KindId::ALL, [KindId::FieldA, KindId::FieldC, KindId::FieldD]);
+    assert_eq!(KindId::NAMES, ["field_a", "field_c", "field_d"]);
+    assert_eq!(Kind::as_vec(), vec![Kind::FieldA, Kind::FieldC, Kind::FieldD]);
+    assert_eq!(Kind::FieldD.id(), KindId::FieldD);
+    assert_eq!(KindId::FieldD.to_string(), "field_d");
+    assert_eq!("field_c".parse::<KindId>(), Ok(KindId::FieldC));
+    let err = "field_b".parse::<KindId>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown KindId \"field_b\"; expected one of: \"field_a\", \"field_c\", \"field_d\""
+    );
+
+    assert_eq!(KindId::FieldD.as_u8(), 2);
+    assert_eq!(KindId::FieldD as u8, 2);
+    assert_eq!(KindId::try_from(1u8), Ok(KindId::FieldC));
+    assert_eq!(KindId::try_from(3u8), Err(3));
+
+    let set = kind_id_set![FieldA, FieldD];
+    assert_eq!(set.bits(), 0b101);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [KindId::FieldA, KindId::FieldD]);
+    assert_eq!(KindIdSet::all().len(), 3);
+
+    let map = KindIdMap::from_fn(|id| id.to_string());
+    assert_eq!(map[KindId::FieldD], "field_d");
+    assert_eq!(map.keys().collect::<Vec<_>>(), KindId::ALL);
+
+    assert_eq!(
+        Event::Inner(Kind::FieldC).deep_id().to_string(),
+        "Inner.field_c"
+    );
+    assert_eq!(EventPath::as_vec().len(), 4);
+    assert_eq!("tick".parse::<EventPath>(), Ok(EventPath::Tick));
+    assert_eq!(EventId::COUNT, 2);
+}
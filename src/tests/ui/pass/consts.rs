@@ -0,0 +1,22 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(display_variant_snake)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    #[enum_ids(rename = "second")]
+    FieldB { value: String },
+    #[enum_ids(skip)]
+    Hidden,
+    C,
+}
+
+const TABLE: [u32; KindId::COUNT] = [10, 20, 30];
+
+fn main() {
+    assert_eq!(KindId::COUNT, 3);
+    assert_eq!(KindId::ALL, [KindId::FieldA, KindId::FieldB, KindId::C]);
+    assert_eq!(KindId::NAMES, ["field_a", "second", "c"]);
+    assert_eq!(TABLE.len(), KindId::COUNT);
+    assert_eq!(KindId::as_vec(), KindId::ALL.to_vec());
+}
@@ -0,0 +1,42 @@
+#![deny(deprecated)]
+
+use enum_ids::enum_ids;
+
+#[enum_ids(display_variant_snake, parse, repr, set, map)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    Current(i32),
+    #[deprecated(since = "0.2.0", note = "use Current")]
+    Legacy(i32),
+    #[deprecated = "will be removed"]
+    Old,
+    #[deprecated]
+    Ancient,
+}
+
+#[enum_ids(display_variant_snake)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Event {
+    #[enum_ids(nested)]
+    Inner(Kind),
+    #[deprecated]
+    Tick,
+}
+
+fn main() {
+    let id: KindId = "legacy".parse().unwrap();
+    assert!(id.is_deprecated());
+    assert_eq!(id.deprecation_note(), Some("use Current"));
+    let id: KindId = "old".parse().unwrap();
+    assert_eq!(id.deprecation_note(), Some("will be removed"));
+    let id: KindId = "ancient".parse().unwrap();
+    assert!(id.is_deprecated());
+    assert_eq!(id.deprecation_note(), None);
+    let id: KindId = "current".parse().unwrap();
+    assert!(!id.is_deprecated());
+    assert_eq!(id.deprecation_note(), None);
+    assert_eq!(
+        KindId::ALL.iter().filter(|id| id.is_deprecated()).count(),
+        3
+    );
+}
@@ -7,6 +7,7 @@ pub enum Kind {
     ThisIsFieldB { value: String },
     C,
     ABC,
+    HTTPServer,
 }
 
 fn main() {
@@ -15,5 +16,6 @@ fn main() {
     assert_eq!(KindId::FieldA.to_string(), "field_a");
     assert_eq!(KindId::ThisIsFieldB.to_string(), "this_is_field_b");
     assert_eq!(KindId::C.to_string(), "c");
-    assert_eq!(KindId::ABC.to_string(), "a_b_c");
+    assert_eq!(KindId::ABC.to_string(), "abc");
+    assert_eq!(KindId::HTTPServer.to_string(), "http_server");
 }
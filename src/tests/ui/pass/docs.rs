@@ -0,0 +1,40 @@
+//! Generated items have docs.
+#![deny(missing_docs)]
+
+use enum_ids::enum_ids;
+
+/// Kind of value.
+#[enum_ids(public, iterator, parse, repr, set, map, display_variant)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    /// First value.
+    FieldA,
+    /// Second value.
+    FieldB,
+    /// Third value.
+    C,
+}
+
+/// Events with nested kinds.
+#[enum_ids(public, doc = "Kinds of events.")]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Event {
+    /// Wraps a kind.
+    #[enum_ids(nested)]
+    Inner(Kind),
+    /// Other event.
+    Other(u8),
+}
+
+#[allow(missing_docs)]
+#[enum_ids(public)]
+pub enum Undocumented {
+    A(i32),
+    B,
+}
+
+fn main() {
+    let _ = Kind::as_vec();
+    let _ = Event::Other(1).deep_id();
+    let _ = Undocumented::B.id();
+}
@@ -0,0 +1,73 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(iter)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    A(i32),
+    B { value: String },
+    #[cfg(any())]
+    Hidden,
+    C,
+}
+
+#[enum_ids(iter = "ids", iter_all = "AllCustom")]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Custom {
+    X,
+    Y,
+}
+
+impl CustomId {
+    pub fn iter(&self) -> &'static str {
+        "user method"
+    }
+}
+
+pub struct CustomIds;
+
+#[enum_ids]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Plain {
+    X,
+}
+
+pub struct PlainIds;
+
+impl PlainId {
+    pub fn iter() -> std::vec::IntoIter<PlainId> {
+        PlainId::as_vec().into_iter()
+    }
+}
+
+fn main() {
+    let all: Vec<KindId> = KindId::iter().collect();
+    assert_eq!(all, KindId::as_vec());
+
+    let reversed: Vec<KindId> = KindId::iter().rev().collect();
+    assert_eq!(reversed, vec![KindId::C, KindId::B, KindId::A]);
+
+    let mut iter = KindId::iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(KindId::A));
+    assert_eq!(iter.next_back(), Some(KindId::C));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(KindId::B));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(KindId::iter().nth(2), Some(KindId::C));
+    assert_eq!(KindId::iter().nth(3), None);
+
+    let mut names = Vec::new();
+    for id in KindIds {
+        names.push(format!("{id:?}"));
+    }
+    assert_eq!(names, ["A", "B", "C"]);
+    assert_eq!(KindIds.into_iter().len(), KindId::COUNT);
+
+    assert_eq!(CustomId::ids().collect::<Vec<_>>(), vec![CustomId::X, CustomId::Y]);
+    assert_eq!(AllCustom.into_iter().last(), Some(CustomId::Y));
+    assert_eq!(PlainId::iter().collect::<Vec<_>>(), vec![PlainId::X]);
+    let _ = (CustomIds, PlainIds);
+    assert_eq!(CustomId::X.iter(), "user method");
+}
@@ -0,0 +1,64 @@
+use enum_ids::enum_ids;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[enum_ids(iterator)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Kind {
+    A(i32, String),
+    B { value: Option<u8> },
+    #[enum_ids(sample = Kind::C(Point { x: 1, y: 2 }))]
+    C(Point),
+    #[cfg(any())]
+    Hidden(Point),
+    D,
+}
+
+#[enum_ids(iterator)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Wrapper<T: Default> {
+    Value(T),
+    #[enum_ids(sample = Wrapper::Empty)]
+    Empty,
+}
+
+#[enum_ids(iterator)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Holder<T, U> {
+    Value(T),
+    Pair { first: Option<U>, second: Vec<T> },
+    Empty,
+}
+
+fn main() {
+    assert_eq!(
+        Kind::as_vec(),
+        vec![
+            Kind::A(0, String::new()),
+            Kind::B { value: None },
+            Kind::C(Point { x: 1, y: 2 }),
+            Kind::D,
+        ]
+    );
+    assert_eq!(
+        Wrapper::<u8>::as_vec(),
+        vec![Wrapper::Value(0), Wrapper::Empty]
+    );
+    assert_eq!(
+        Holder::<u8, Point>::as_vec(),
+        vec![
+            Holder::Value(0),
+            Holder::Pair {
+                first: None,
+                second: Vec::new()
+            },
+            Holder::Empty,
+        ]
+    );
+    let point: Holder<Point, Point> = Holder::Empty;
+    assert_eq!(point.id(), HolderId::Empty);
+}
@@ -0,0 +1,40 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(map)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    A(i32),
+    B { value: String },
+    C,
+    #[enum_ids(skip)]
+    Hidden,
+}
+
+fn main() {
+    let mut limits: KindIdMap<u32> = KindIdMap::default();
+    assert_eq!(limits.len(), KindId::COUNT);
+    assert_eq!(limits[KindId::B], 0);
+    limits[KindId::B] = 10;
+    assert_eq!(limits.insert(KindId::C, 20), 0);
+    assert_eq!(limits.as_array(), &[0, 10, 20]);
+    assert_eq!(
+        limits.iter().collect::<Vec<(KindId, &u32)>>(),
+        vec![(KindId::A, &0), (KindId::B, &10), (KindId::C, &20)]
+    );
+    for (_, value) in &mut limits {
+        *value += 1;
+    }
+    assert_eq!(limits.values().copied().collect::<Vec<u32>>(), vec![1, 11, 21]);
+    assert_eq!(limits.keys().collect::<Vec<KindId>>(), KindId::as_vec());
+
+    let names = KindIdMap::from_fn(|id| format!("{id:?}"));
+    assert_eq!(names[KindId::A], "A");
+    assert_eq!(format!("{:?}", names), r#"{"A": "A", "B": "B", "C": "C"}"#);
+
+    let lengths = names.map(|_, name| name.len());
+    assert_eq!(lengths.into_array(), [1, 1, 1]);
+    assert_eq!(
+        KindIdMap::from_array([1, 2, 3]).into_iter().last(),
+        Some((KindId::C, 3))
+    );
+}
@@ -0,0 +1,13 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(name = "{}Kind", getter = "kind")]
+#[derive(Debug, PartialEq)]
+pub enum Shape {
+    Circle(f32),
+    Square,
+}
+
+fn main() {
+    assert_eq!(Shape::Circle(1.0).kind(), ShapeKind::Circle);
+    assert_eq!(Shape::Square.kind(), ShapeKind::Square);
+}
@@ -0,0 +1,93 @@
+use enum_ids::{enum_ids, HasId};
+use std::str::FromStr;
+
+#[enum_ids(display_variant_snake)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum NetEvent {
+    Connect(String),
+    Disconnect,
+}
+
+#[enum_ids(display_variant_snake)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum DiskEvent {
+    Read(u64),
+    Write(u64),
+    Full,
+}
+
+#[enum_ids(display_variant_snake)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Event {
+    #[enum_ids(nested)]
+    Net(NetEvent),
+    #[enum_ids(nested)]
+    Disk(DiskEvent),
+    Shutdown { code: i32 },
+    Tick(u32),
+}
+
+#[enum_ids(no_derive, panic_on_skip)]
+pub enum Signal {
+    Stop,
+    #[enum_ids(skip)]
+    Internal,
+}
+
+#[enum_ids(no_derive)]
+pub enum Command {
+    #[enum_ids(nested)]
+    Signal(Signal),
+    Run,
+}
+
+fn main() {
+    let event = Event::Net(NetEvent::Connect(String::from("localhost")));
+    assert_eq!(event.id(), EventId::Net);
+    assert_eq!(event.deep_id(), EventPath::Net(NetEventId::Connect));
+    assert_eq!(event.deep_id().id(), EventId::Net);
+    assert_eq!(event.deep_id().to_string(), "net.connect");
+    assert_eq!(Event::Disk(DiskEvent::Full).deep_id().to_string(), "disk.full");
+    assert_eq!(Event::Shutdown { code: 0 }.deep_id(), EventPath::Shutdown);
+    assert_eq!(Event::Tick(1).deep_id().to_string(), "tick");
+    assert_eq!(NetEvent::Disconnect.id(), <NetEvent as HasId>::id(&NetEvent::Disconnect));
+
+    assert_eq!(
+        EventPath::from_str("disk.write"),
+        Ok(EventPath::Disk(DiskEventId::Write))
+    );
+    assert_eq!("shutdown".parse::<EventPath>(), Ok(EventPath::Shutdown));
+    assert!("net".parse::<EventPath>().is_err());
+    assert!("tick.read".parse::<EventPath>().is_err());
+    let err = "net.read".parse::<EventPath>().unwrap_err();
+    assert_eq!(err.value(), "net.read");
+    assert_eq!(err.to_string(), "unknown EventPath \"net.read\"");
+
+    let all: Vec<String> = EventPath::as_vec().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        all,
+        [
+            "net.connect",
+            "net.disconnect",
+            "disk.read",
+            "disk.write",
+            "disk.full",
+            "shutdown",
+            "tick"
+        ]
+    );
+    for path in EventPath::as_vec() {
+        assert_eq!(path.to_string().parse::<EventPath>(), Ok(path));
+    }
+
+    assert!(matches!(
+        Command::Signal(Signal::Stop).deep_id(),
+        CommandPath::Signal(SignalId::Stop)
+    ));
+    let all: Vec<String> = CommandPath::as_vec().iter().map(ToString::to_string).collect();
+    assert_eq!(all, ["Signal.Stop", "Run"]);
+    assert!(matches!(
+        "Signal.Stop".parse::<CommandPath>(),
+        Ok(CommandPath::Signal(SignalId::Stop))
+    ));
+}
@@ -0,0 +1,85 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(ordinal)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Step {
+    Start,
+    #[cfg(any())]
+    Hidden,
+    Details(String),
+    Confirm { accepted: bool },
+    Done,
+}
+
+#[enum_ids(ordinal, derive = "Debug, Clone, Copy, PartialEq")]
+pub enum Phase {
+    First,
+    Second(String),
+    Third,
+}
+
+#[enum_ids(predicates, iter)]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Level {
+    Low,
+    High(u8),
+}
+
+impl LevelId {
+    fn index(&self) -> u8 {
+        match self {
+            LevelId::Low => 1,
+            LevelId::High => 2,
+        }
+    }
+
+    fn next(&self) -> Self {
+        LevelId::High
+    }
+}
+
+const SECOND: Option<StepId> = StepId::from_index(1);
+const AFTER_FIRST: Option<PhaseId> = PhaseId::First.next();
+
+fn main() {
+    assert_eq!(SECOND, Some(StepId::Details));
+    assert_eq!(StepId::Start.index(), 0);
+    assert_eq!(StepId::Done.index(), 3);
+    assert_eq!(StepId::from_index(4), None);
+
+    assert_eq!(StepId::Start.next(), Some(StepId::Details));
+    assert_eq!(StepId::Done.next(), None);
+    assert_eq!(StepId::Details.prev(), Some(StepId::Start));
+    assert_eq!(StepId::Start.prev(), None);
+
+    assert_eq!(StepId::Done.next_cyclic(), StepId::Start);
+    assert_eq!(StepId::Start.prev_cyclic(), StepId::Done);
+    assert_eq!(StepId::Details.next_cyclic(), StepId::Confirm);
+
+    assert_eq!(
+        StepId::range(StepId::Details..=StepId::Done).collect::<Vec<_>>(),
+        vec![StepId::Details, StepId::Confirm, StepId::Done]
+    );
+    assert_eq!(
+        StepId::range(StepId::Start..StepId::Confirm).collect::<Vec<_>>(),
+        vec![StepId::Start, StepId::Details]
+    );
+    assert_eq!(
+        StepId::range(StepId::Confirm..).rev().collect::<Vec<_>>(),
+        vec![StepId::Done, StepId::Confirm]
+    );
+    assert_eq!(StepId::range(..).len(), StepId::COUNT);
+    assert_eq!(StepId::range(StepId::Done..StepId::Start).count(), 0);
+
+    let phase = PhaseId::Second;
+    assert_eq!(phase.index(), 1);
+    assert_eq!(phase.next(), Some(PhaseId::Third));
+    assert_eq!(phase.prev_cyclic(), PhaseId::First);
+    assert_eq!(AFTER_FIRST, Some(phase));
+
+    assert_eq!(LevelId::High.index(), 2);
+    assert_eq!(LevelId::Low.next(), LevelId::High);
+    assert_eq!(LevelId::iter().rev().collect::<Vec<_>>(), vec![LevelId::High, LevelId::Low]);
+    assert!(Level::High(1).is_one_of(&[LevelId::High]));
+    assert!(!Level::Low.is_one_of(&[LevelId::High]));
+}
@@ -0,0 +1,26 @@
+use enum_ids::enum_ids;
+use std::convert::TryFrom;
+
+#[enum_ids(display_variant_snake, parse)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    ThisIsFieldB { value: String },
+    C,
+}
+
+fn main() {
+    assert_eq!("field_a".parse::<KindId>(), Ok(KindId::FieldA));
+    assert_eq!(KindId::try_from("this_is_field_b"), Ok(KindId::ThisIsFieldB));
+    assert_eq!(KindId::try_from(String::from("c")), Ok(KindId::C));
+    for id in KindId::as_vec() {
+        assert_eq!(id.to_string().parse::<KindId>(), Ok(id));
+    }
+    let err = "FieldA".parse::<KindId>().unwrap_err();
+    assert_eq!(err.value(), "FieldA");
+    assert_eq!(KindIdParseError::EXPECTED, &["field_a", "this_is_field_b", "c"]);
+    assert_eq!(
+        err.to_string(),
+        "unknown KindId \"FieldA\"; expected one of: \"field_a\", \"this_is_field_b\", \"c\""
+    );
+}
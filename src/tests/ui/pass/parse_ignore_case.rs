@@ -0,0 +1,16 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(display, parse_ignore_case)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    A(i32),
+    B { value: String },
+    C,
+}
+
+fn main() {
+    assert_eq!("Kind :: A".parse::<KindId>(), Ok(KindId::A));
+    assert_eq!("kind :: b".parse::<KindId>(), Ok(KindId::B));
+    assert_eq!("KIND :: C".parse::<KindId>(), Ok(KindId::C));
+    assert!("C".parse::<KindId>().is_err());
+}
@@ -0,0 +1,40 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(predicates, set)]
+pub enum Kind<T> {
+    A(T),
+    HTTPServer { port: u16 },
+    Type,
+    #[cfg(any())]
+    Hidden,
+    #[enum_ids(skip)]
+    Internal,
+}
+
+#[enum_ids(predicates)]
+pub enum Plain {
+    First,
+    Second(i32),
+}
+
+const IS_FIRST: bool = Plain::First.is_first();
+
+fn main() {
+    assert!(IS_FIRST);
+    assert!(Plain::Second(1).is_second());
+    assert!(!Plain::Second(1).is_first());
+
+    let kind: Kind<String> = Kind::HTTPServer { port: 80 };
+    assert!(kind.is_http_server());
+    assert!(!kind.is_a());
+    assert!(Kind::<u8>::Type.is_type());
+    assert!(Kind::<u8>::Internal.is_internal());
+
+    assert!(kind.is_one_of(&[KindId::A, KindId::HTTPServer]));
+    assert!(!kind.is_one_of(&[KindId::Type]));
+    assert!(!Kind::<u8>::Internal.is_one_of(&[KindId::A, KindId::HTTPServer, KindId::Type]));
+
+    assert!(kind.is_in_set(&kind_id_set![HTTPServer, Type]));
+    assert!(!kind.is_in_set(&KindIdSet::new()));
+    assert!(!Kind::<u8>::Internal.is_in_set(&KindIdSet::all()));
+}
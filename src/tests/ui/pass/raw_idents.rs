@@ -0,0 +1,15 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(getter = "type", derive = "Debug, Clone, std::cmp::PartialEq")]
+#[derive(Debug)]
+pub enum Kind {
+    #[enum_ids(rename_id = "r#Match")]
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(Kind::A.r#type(), KindId::Match);
+    assert_eq!(Kind::B.r#type(), KindId::B);
+    assert_eq!(KindId::B.clone(), KindId::B);
+}
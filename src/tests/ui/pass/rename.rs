@@ -0,0 +1,32 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(display_variant_snake, parse)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    #[enum_ids(rename = "second")]
+    FieldB { value: String },
+    #[enum_ids(rename = "3rd", rename_id = "Third")]
+    FieldC,
+}
+
+#[enum_ids(display)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Other {
+    #[enum_ids(rename = "first")]
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(Kind::FieldC.id(), KindId::Third);
+    assert_eq!(KindId::FieldA.to_string(), "field_a");
+    assert_eq!(KindId::FieldB.to_string(), "second");
+    assert_eq!(KindId::Third.to_string(), "3rd");
+    assert_eq!("second".parse::<KindId>(), Ok(KindId::FieldB));
+    assert_eq!("3rd".parse::<KindId>(), Ok(KindId::Third));
+    assert!("field_b".parse::<KindId>().is_err());
+
+    assert_eq!(OtherId::A.to_string(), "Other :: first");
+    assert_eq!(OtherId::B.to_string(), "Other :: B");
+}
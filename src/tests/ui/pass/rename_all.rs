@@ -0,0 +1,178 @@
+use enum_ids::enum_ids;
+
+mod snake {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "snake_case")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["http_server", "io_error2_retry", "version2", "abc"]);
+    }
+}
+
+mod kebab {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "kebab-case")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["http-server", "io-error2-retry", "version2", "abc"]);
+    }
+}
+
+mod screaming_snake {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "SCREAMING_SNAKE_CASE")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["HTTP_SERVER", "IO_ERROR2_RETRY", "VERSION2", "ABC"]);
+    }
+}
+
+mod screaming_kebab {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "SCREAMING-KEBAB-CASE")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["HTTP-SERVER", "IO-ERROR2-RETRY", "VERSION2", "ABC"]);
+    }
+}
+
+mod camel {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "camelCase")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["httpServer", "ioError2Retry", "version2", "abc"]);
+    }
+}
+
+mod pascal {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "PascalCase")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["HttpServer", "IoError2Retry", "Version2", "Abc"]);
+    }
+}
+
+mod lower {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "lowercase")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["httpserver", "ioerror2retry", "version2", "abc"]);
+    }
+}
+
+mod upper {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "UPPERCASE")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["HTTPSERVER", "IOERROR2RETRY", "VERSION2", "ABC"]);
+    }
+}
+
+mod title {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(display_variant, rename_all = "Title Case")]
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Kind {
+        HTTPServer,
+        IOError2Retry,
+        Version2,
+        ABC,
+    }
+
+    pub fn check() {
+        assert_eq!(KindId::NAMES, ["Http Server", "Io Error2 Retry", "Version2", "Abc"]);
+    }
+}
+
+#[enum_ids(display_variant, rename_all = "kebab-case", parse)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    #[enum_ids(rename = "second")]
+    FieldB { value: String },
+}
+
+fn main() {
+    snake::check();
+    kebab::check();
+    screaming_snake::check();
+    screaming_kebab::check();
+    camel::check();
+    pascal::check();
+    lower::check();
+    upper::check();
+    title::check();
+
+    assert_eq!(KindId::FieldA.to_string(), "field-a");
+    assert_eq!(KindId::FieldB.to_string(), "second");
+    assert_eq!("field-a".parse::<KindId>(), Ok(KindId::FieldA));
+}
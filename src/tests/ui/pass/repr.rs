@@ -0,0 +1,32 @@
+use enum_ids::enum_ids;
+use std::convert::TryFrom;
+
+#[enum_ids(repr)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    A(i32),
+    B { value: String },
+    C,
+}
+
+#[enum_ids(repr = "i32")]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Other {
+    A(i32),
+    B { value: String },
+    C,
+}
+
+fn main() {
+    assert_eq!(std::mem::size_of::<KindId>(), 1);
+    assert_eq!(KindId::B.as_u8(), 1);
+    assert_eq!(u8::from(KindId::C), 2);
+    assert_eq!(KindId::try_from(0u8), Ok(KindId::A));
+    assert_eq!(KindId::try_from(3u8), Err(3));
+
+    assert_eq!(std::mem::size_of::<OtherId>(), 4);
+    assert_eq!(OtherId::C.as_i32(), 2);
+    assert_eq!(i32::from(OtherId::A), 0);
+    assert_eq!(OtherId::try_from(1), Ok(OtherId::B));
+    assert_eq!(OtherId::try_from(-1), Err(-1));
+}
@@ -0,0 +1,50 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(set)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    A(i32),
+    B { value: String },
+    C,
+    #[enum_ids(skip)]
+    Hidden,
+}
+
+const ENABLED: KindIdSet = kind_id_set![A, C];
+
+fn main() {
+    assert_eq!(std::mem::size_of::<KindIdSet>(), 1);
+    let mut set = KindIdSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(KindId::B));
+    assert!(!set.insert(KindId::B));
+    assert!(set.contains(KindId::B));
+    assert!(!set.contains(KindId::A));
+    assert_eq!(set.len(), 1);
+    assert!(set.remove(KindId::B));
+    assert!(!set.remove(KindId::B));
+
+    assert_eq!(ENABLED.iter().collect::<Vec<KindId>>(), vec![KindId::A, KindId::C]);
+    assert_eq!(ENABLED.iter().rev().collect::<Vec<KindId>>(), vec![KindId::C, KindId::A]);
+    assert_eq!(ENABLED.complement(), kind_id_set![B]);
+    assert_eq!(!ENABLED, kind_id_set![B]);
+    assert_eq!(ENABLED.union(kind_id_set![B]), KindIdSet::all());
+    assert_eq!(ENABLED | kind_id_set![B], KindIdSet::all());
+    assert_eq!(ENABLED.intersection(kind_id_set![C, B]), kind_id_set![C]);
+    assert_eq!(ENABLED & kind_id_set![C, B], kind_id_set![C]);
+    assert_eq!(ENABLED.difference(kind_id_set![C]), kind_id_set![A]);
+    assert_eq!(ENABLED - kind_id_set![C], kind_id_set![A]);
+    assert!(kind_id_set![A].is_subset(&ENABLED));
+    assert!(KindIdSet::all().is_superset(&ENABLED));
+    assert!(ENABLED.is_disjoint(&kind_id_set![B]));
+    assert_eq!(kind_id_set![].len(), 0);
+    assert_eq!(KindIdSet::all().len(), KindId::COUNT);
+
+    let collected: KindIdSet = vec![KindId::C, KindId::A].into_iter().collect();
+    assert_eq!(collected, ENABLED);
+    assert_eq!(format!("{:?}", ENABLED), "{A, C}");
+    assert_eq!(ENABLED.iter().len(), 2);
+    for id in &ENABLED {
+        assert!(ENABLED.contains(id));
+    }
+}
@@ -0,0 +1,25 @@
+#[macro_use]
+mod kinds {
+    use enum_ids::enum_ids;
+
+    #[enum_ids(set)]
+    pub enum Kind {
+        A,
+        B,
+        C,
+    }
+
+    pub mod defaults {
+        use super::{KindId, KindIdSet};
+
+        pub const ENABLED: KindIdSet = kind_id_set![A, C];
+    }
+}
+
+use kinds::{KindId, KindIdSet};
+
+fn main() {
+    assert_eq!(kinds::defaults::ENABLED, kind_id_set![A, C]);
+    assert!(kind_id_set![B].contains(KindId::B));
+    assert_eq!(kind_id_set![], KindIdSet::new());
+}
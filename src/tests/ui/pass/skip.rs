@@ -0,0 +1,33 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(display_variant, parse)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    A(i32),
+    #[enum_ids(skip)]
+    B { value: String },
+    C,
+}
+
+#[enum_ids(panic_on_skip)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Other {
+    A(i32),
+    #[enum_ids(skip)]
+    B,
+}
+
+fn main() {
+    assert_eq!(Kind::A(1).id(), Some(KindId::A));
+    assert_eq!(Kind::B { value: String::new() }.id(), None);
+    assert_eq!(KindId::as_vec(), vec![KindId::A, KindId::C]);
+    assert!("B".parse::<KindId>().is_err());
+    for id in KindId::as_vec() {
+        match id {
+            KindId::A | KindId::C => {}
+        }
+    }
+
+    assert_eq!(Other::A(1).id(), OtherId::A);
+    assert!(std::panic::catch_unwind(|| Other::B.id()).is_err());
+}
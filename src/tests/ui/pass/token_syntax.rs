@@ -0,0 +1,30 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(
+    derive(Debug, Clone, Copy, std::cmp::PartialEq),
+    name = KindTag,
+    getter = kind,
+    display(variant, rename_all = "snake_case"),
+    parse(ignore_case),
+    repr = u8
+)]
+pub enum Kind {
+    #[enum_ids(rename_id = First)]
+    FirstValue(i32),
+    SecondValue,
+}
+
+#[enum_ids(display(), iter = all, derive = "Debug, PartialEq")]
+pub enum Other {
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(Kind::FirstValue(1).kind(), KindTag::First);
+    assert_eq!(KindTag::SecondValue.to_string(), "second_value");
+    assert_eq!("SECOND_VALUE".parse::<KindTag>(), Ok(KindTag::SecondValue));
+    assert_eq!(u8::from(KindTag::SecondValue), 1);
+    assert_eq!(OtherId::B.to_string(), "Other :: B");
+    assert_eq!(OtherId::all().count(), 2);
+}
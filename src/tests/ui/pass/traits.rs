@@ -0,0 +1,40 @@
+use enum_ids::{enum_ids, HasId, IdEnum};
+
+#[enum_ids(display_variant_snake)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    #[enum_ids(rename = "second")]
+    FieldB { value: String },
+    C,
+}
+
+#[enum_ids(getter = "kind")]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Other<T> {
+    A(T),
+    #[enum_ids(skip)]
+    B,
+}
+
+fn kind_name<T: HasId>(value: &T) -> &'static str
+where
+    T::Id: IdEnum,
+{
+    value.id().name()
+}
+
+fn names<T: IdEnum>() -> Vec<&'static str> {
+    T::all().iter().map(IdEnum::name).collect()
+}
+
+fn main() {
+    assert_eq!(kind_name(&Kind::FieldA(1)), "field_a");
+    assert_eq!(kind_name(&Kind::FieldB { value: String::new() }), "second");
+    assert_eq!(<KindId as IdEnum>::COUNT, 3);
+    assert_eq!(names::<KindId>(), vec!["field_a", "second", "c"]);
+
+    assert_eq!(HasId::id(&Other::A(1)), Some(OtherId::A));
+    assert_eq!(HasId::id(&Other::<u8>::B), None);
+    assert_eq!(names::<OtherId>(), vec!["A"]);
+}
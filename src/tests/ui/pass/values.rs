@@ -0,0 +1,45 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(value(status: u16, retryable: bool = false, label: &'static str = "error"))]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Error {
+    #[enum_ids(value(status = 404))]
+    NotFound { path: String },
+    #[enum_ids(value(status = 503, retryable = true))]
+    Unavailable(u32),
+    #[enum_ids(value(status = 500, label = "internal"))]
+    Internal,
+    #[enum_ids(skip, value(status = 0))]
+    Unknown,
+}
+
+#[enum_ids]
+#[derive(Debug, PartialEq, Clone)]
+pub enum Shape {
+    #[enum_ids(value(sides: u8 = 3))]
+    Triangle,
+    #[enum_ids(value(sides = 4))]
+    Square,
+}
+
+const NOT_FOUND: u16 = ErrorId::NotFound.status();
+
+fn main() {
+    assert_eq!(NOT_FOUND, 404);
+    assert_eq!(ErrorId::Unavailable.status(), 503);
+    assert!(ErrorId::Unavailable.retryable());
+    assert!(!ErrorId::Internal.retryable());
+    assert_eq!(ErrorId::NotFound.label(), "error");
+    assert_eq!(ErrorId::Internal.label(), "internal");
+
+    let error = Error::NotFound {
+        path: String::from("/"),
+    };
+    assert_eq!(error.status(), 404);
+    assert!(!error.retryable());
+    assert_eq!(Error::Unavailable(1).status(), 503);
+    assert_eq!(Error::Unknown.status(), 0);
+
+    assert_eq!(ShapeId::Triangle.sides(), 3);
+    assert_eq!(Shape::Square.sides(), 4);
+}
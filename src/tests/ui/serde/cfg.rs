@@ -0,0 +1,21 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(serde = "index", serde_any, map)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    #[cfg(any())]
+    FieldB,
+    FieldC,
+}
+
+fn main() {
+    assert_eq!(serde_json::to_string(&KindId::FieldC).unwrap(), "1");
+    assert_eq!(serde_json::from_str::<KindId>("1").unwrap(), KindId::FieldC);
+    assert_eq!(serde_json::from_str::<KindId>(r#""FieldC""#).unwrap(), KindId::FieldC);
+    assert!(serde_json::from_str::<KindId>(r#""FieldB""#).is_err());
+    assert!(serde_json::from_str::<KindId>("2").is_err());
+
+    let map = KindIdMap::from_array([1, 2]);
+    assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"FieldA":1,"FieldC":2}"#);
+}
@@ -0,0 +1,34 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(serde = "index")]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    FieldB { value: String },
+    C,
+}
+
+#[enum_ids(repr = "i16", serde = "index", derive = "Debug, PartialEq")]
+pub enum Signed {
+    A(i32),
+    B,
+}
+
+fn main() {
+    assert_eq!(serde_json::to_string(&KindId::C).unwrap(), "2");
+    assert_eq!(
+        serde_json::to_string(&vec![KindId::FieldB, KindId::FieldA]).unwrap(),
+        "[1,0]"
+    );
+    assert_eq!(serde_json::from_str::<KindId>("1").unwrap(), KindId::FieldB);
+    let err = serde_json::from_str::<KindId>("3").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid value: integer `3`, expected index of KindId"));
+    let err = serde_json::from_str::<KindId>(r#""FieldA""#).unwrap_err();
+    assert!(err.to_string().contains("invalid type: string"));
+
+    assert_eq!(serde_json::to_string(&SignedId::B).unwrap(), "1");
+    assert_eq!(serde_json::from_str::<SignedId>("0").unwrap(), SignedId::A);
+    assert!(serde_json::from_str::<SignedId>("-1").is_err());
+}
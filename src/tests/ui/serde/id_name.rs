@@ -0,0 +1,44 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(display_variant_snake, serde = "name")]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    #[enum_ids(rename = "second")]
+    FieldB { value: String },
+    HTTPServer,
+}
+
+#[enum_ids(rename_all = "kebab-case", serde = "name", serde_any, parse_ignore_case)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Message {
+    Ping,
+    PongReply(u8),
+}
+
+fn main() {
+    assert_eq!(serde_json::to_string(&KindId::FieldA).unwrap(), r#""field_a""#);
+    assert_eq!(serde_json::to_string(&KindId::FieldB).unwrap(), r#""second""#);
+    assert_eq!(serde_json::to_string(&KindId::HTTPServer).unwrap(), r#""http_server""#);
+    assert_eq!(
+        serde_json::from_str::<KindId>(r#""http_server""#).unwrap(),
+        KindId::HTTPServer
+    );
+    let err = serde_json::from_str::<KindId>(r#""FieldA""#).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("unknown variant `FieldA`, expected one of `field_a`, `second`, `http_server`"));
+    let err = serde_json::from_str::<KindId>("0").unwrap_err();
+    assert!(err.to_string().contains("expected name of KindId"));
+
+    assert_eq!(serde_json::to_string(&MessageId::PongReply).unwrap(), r#""pong-reply""#);
+    assert_eq!(
+        serde_json::from_str::<MessageId>(r#""PONG-reply""#).unwrap(),
+        MessageId::PongReply
+    );
+    assert_eq!(serde_json::from_str::<MessageId>("1").unwrap(), MessageId::PongReply);
+    let err = serde_json::from_str::<MessageId>("2").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid value: integer `2`, expected name or index of MessageId"));
+}
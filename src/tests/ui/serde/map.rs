@@ -0,0 +1,21 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(map, display_variant_snake)]
+#[derive(Debug, PartialEq, Clone, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    FieldA(i32),
+    FieldB { value: String },
+}
+
+fn main() {
+    let map = KindIdMap::from_array([1, 2]);
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(json, r#"{"field_a":1,"field_b":2}"#);
+    let restored: KindIdMap<u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, map);
+
+    let err = serde_json::from_str::<KindIdMap<u32>>(r#"{"field_a":1}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `field_b`"));
+    let err = serde_json::from_str::<KindIdMap<u32>>(r#"{"field_a":1,"other":2}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown field `other`"));
+}
@@ -0,0 +1,14 @@
+use enum_ids::enum_ids;
+
+#[enum_ids(serde = "name", derive(Debug, serde::Serialize))]
+pub enum Explicit {
+    A,
+}
+
+#[enum_ids(serde = "index")]
+#[derive(Debug, serde::Deserialize)]
+pub enum Inherited {
+    A,
+}
+
+fn main() {}